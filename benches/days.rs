@@ -0,0 +1,63 @@
+//! Benchmarks each day's generator and parts against the real inputs, one group per day. Any
+//! [`Alternative`](aoc::solution::Alternative) a day lists is benchmarked next to the part it
+//! replaces.
+//!
+//! Usage: `cargo bench --bench days [FILTER]`, e.g. `cargo bench --bench days -- y2023_day16/`
+//! for one day or `cargo bench --bench days -- part2` for the second part of every day.
+
+use std::{fs, hint::black_box};
+
+use aoc::{
+    solution::{self, Alternative, Solution},
+    y2023,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let path = solution::find(S::YEAR, S::DAY)
+        .expect("a registered day")
+        .input_path();
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!(
+            "{} day {}: no input at {}, skipping",
+            S::YEAR,
+            S::DAY,
+            path.display()
+        );
+        return;
+    };
+    let input = input.trim_end_matches('\n');
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("{err}"));
+
+    let mut group = c.benchmark_group(format!("y{}_day{}", S::YEAR, S::DAY));
+    group.bench_function("generator", |b| b.iter(|| S::parse(black_box(input))));
+
+    let defaults = [1, 2].map(|part| Alternative {
+        part,
+        name: "default",
+        solve: if part == 1 { S::part1 } else { S::part2 },
+    });
+    for alt in defaults.iter().chain(S::ALTERNATIVES) {
+        let id = BenchmarkId::new(format!("part{}", alt.part), alt.name);
+        group.bench_with_input(id, &parsed, |b, input| {
+            b.iter(|| (alt.solve)(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = y2023;
+    // Several parts take seconds, so keep to criterion's smallest sample.
+    config = Criterion::default().sample_size(10);
+    targets = bench::<y2023::day1::Day1>, bench::<y2023::day2::Day2>, bench::<y2023::day3::Day3>,
+        bench::<y2023::day4::Day4>, bench::<y2023::day5::Day5>, bench::<y2023::day6::Day6>,
+        bench::<y2023::day7::Day7>, bench::<y2023::day8::Day8>, bench::<y2023::day9::Day9>,
+        bench::<y2023::day10::Day10>, bench::<y2023::day11::Day11>, bench::<y2023::day12::Day12>,
+        bench::<y2023::day13::Day13>, bench::<y2023::day14::Day14>, bench::<y2023::day15::Day15>,
+        bench::<y2023::day16::Day16>, bench::<y2023::day17::Day17>, bench::<y2023::day18::Day18>,
+        bench::<y2023::day19::Day19>, bench::<y2023::day20::Day20>, bench::<y2023::day21::Day21>,
+        bench::<y2023::day22::Day22>, bench::<y2023::day23::Day23>, bench::<y2023::day24::Day24>,
+        bench::<y2023::day25::Day25>
+}
+criterion_main!(y2023);
@@ -0,0 +1,93 @@
+//! Turns every example under `input/YEAR/examples/dayN/` into a test in `tests/examples.rs`.
+//! Each day's `answers.txt` lists `EXAMPLE PART ANSWER`, where `EXAMPLE.txt` is the input beside
+//! it.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const INPUT: &str = "input";
+
+fn main() {
+    println!("cargo:rerun-if-changed={INPUT}");
+
+    let mut tests = String::new();
+    for year_dir in subdirs(Path::new(INPUT)) {
+        let Ok(year) = year_dir
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse::<u16>()
+        else {
+            continue;
+        };
+        for dir in subdirs(&year_dir.join("examples")) {
+            add_tests(&mut tests, year, &dir);
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+fn add_tests(tests: &mut String, year: u16, dir: &Path) {
+    let name = dir.file_name().unwrap().to_str().unwrap();
+    let day: u8 = name
+        .strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .unwrap_or_else(|| panic!("{}: expected a dayN directory", dir.display()));
+    let answers = dir.join("answers.txt");
+    let text =
+        fs::read_to_string(&answers).unwrap_or_else(|err| panic!("{}: {err}", answers.display()));
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = || {
+            panic!(
+                "{}:{}: expected \"EXAMPLE PART ANSWER\"",
+                answers.display(),
+                i + 1
+            )
+        };
+        let mut fields = line.splitn(3, ' ');
+        let (Some(example), Some(part), Some(answer)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            bad_line()
+        };
+        let part: u8 = part.parse().unwrap_or_else(|_| bad_line());
+        let input = dir.join(format!("{example}.txt"));
+        if !input.is_file() {
+            panic!("{}:{}: no {}", answers.display(), i + 1, input.display());
+        }
+        let input = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(input);
+
+        writeln!(
+            tests,
+            "#[test]\nfn y{year}_{name}_{example}_part{part}() {{\n    \
+             check({year}, {day}, {part}, include_str!({input:?}), {answer:?});\n}}\n",
+            example = example.replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+        )
+        .unwrap();
+    }
+}
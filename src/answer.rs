@@ -0,0 +1,108 @@
+use std::fmt;
+
+use num::{BigInt, BigUint, Signed};
+
+/// The answer to either part of any day.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(BigUint),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Compares as written, so `Answer::Unsigned(42) == "42"` whatever the variant.
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        let written = self.to_string();
+        written == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+macro_rules! from_int {
+    ($variant:ident as $target:ty: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(n: $ty) -> Self {
+                    Answer::$variant(n as $target)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(Unsigned as u64: u8, u16, u32, u64, usize);
+from_int!(Signed as i64: i8, i16, i32, i64, isize);
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        Answer::Big(n)
+    }
+}
+
+/// As [`Answer::Signed`] where it fits, so it compares the same as the equivalent `i64`.
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        if let Ok(small) = i64::try_from(&n) {
+            Answer::Signed(small)
+        } else if let (false, Some(big)) = (n.is_negative(), n.to_biguint()) {
+            Answer::Big(big)
+        } else {
+            Answer::Text(n.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_and_compares_as_text() {
+        assert_eq!(Answer::from(54940u32), "54940");
+        assert_eq!(Answer::from(-3i64), "-3");
+        let big = Answer::from(BigUint::from(10u8).pow(30));
+        assert_eq!(big, format!("1{}", "0".repeat(30)).as_str());
+        assert_eq!("RJ", Answer::from("RJ"));
+        assert_ne!(Answer::from(12usize), "012");
+        assert_eq!(Answer::Unsigned(7), Answer::from(7u8));
+        assert_eq!(Answer::Signed(-5), Answer::from(BigInt::from(-5)));
+        let huge = BigInt::from(-10).pow(31);
+        assert_eq!(Answer::from(huge.clone()), huge.to_string().as_str());
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    solution::{self, Day},
+};
+
+/// The answers one year's real inputs are known to give, one line per part:
+///
+/// ```text
+/// 1 1 54940
+/// 5 2 fnv1a:5c4f9d0b1e2a7c33
+/// ```
+///
+/// An answer can be kept as a hash so the file doesn't give it away.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8), Recorded>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recorded {
+    Plain(String),
+    Hashed(u64),
+}
+
+impl Recorded {
+    pub fn hashed(answer: &Answer) -> Self {
+        Recorded::Hashed(fnv1a(&answer.to_string()))
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Recorded::Plain(s) => answer == s.as_str(),
+            Recorded::Hashed(hash) => fnv1a(&answer.to_string()) == *hash,
+        }
+    }
+}
+
+impl From<&Answer> for Recorded {
+    fn from(answer: &Answer) -> Self {
+        Recorded::Plain(answer.to_string())
+    }
+}
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recorded::Plain(s) => write!(f, "{s}"),
+            Recorded::Hashed(hash) => write!(f, "fnv1a:{hash:016x}"),
+        }
+    }
+}
+
+/// A part whose answer isn't what was recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub expected: Option<Recorded>,
+    pub actual: Answer,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {} part {}: ", self.year, self.day, self.part)?;
+        match &self.expected {
+            Some(expected) => write!(f, "expected {expected}, got {}", self.actual),
+            None => write!(f, "no recorded answer, got {}", self.actual),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path(year: u16) -> PathBuf {
+        solution::input_dir(year).join("answers.txt")
+    }
+
+    /// Loads the answers file, or an empty set if there isn't one yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || format!("line {}: expected \"DAY PART ANSWER\"", i + 1);
+            let mut fields = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(bad_line());
+            };
+            let day = day.parse().map_err(|_| bad_line())?;
+            let part = part.parse().map_err(|_| bad_line())?;
+            let recorded = match answer.strip_prefix("fnv1a:") {
+                Some(hash) => {
+                    Recorded::Hashed(u64::from_str_radix(hash, 16).map_err(|_| bad_line())?)
+                }
+                None => Recorded::Plain(answer.to_string()),
+            };
+            entries.insert((day, part), recorded);
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Recorded> {
+        self.entries.get(&(day, part))
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, recorded: Recorded) {
+        self.entries.insert((day, part), recorded);
+    }
+
+    /// Solves both parts of `day`, which must be from this file's year, and compares them with
+    /// what was recorded.
+    pub fn check(&self, day: &Day, input: &str) -> Result<Vec<Mismatch>, String> {
+        let parsed = (day.parse)(input).map_err(|err| err.to_string())?;
+        Ok([1, 2]
+            .into_iter()
+            .filter_map(|part| {
+                let actual = parsed.solve(part);
+                let expected = self.get(day.day, part);
+                (!expected.is_some_and(|e| e.matches(&actual))).then(|| Mismatch {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    expected: expected.cloned(),
+                    actual,
+                })
+            })
+            .collect())
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# day part answer, as recorded by `cargo run --release --bin record`"
+        )?;
+        for ((day, part), recorded) in &self.entries {
+            writeln!(f, "{day} {part} {recorded}")?;
+        }
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a, which unlike std's hashers is the same on every platform and release.
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let mut answers = Answers::default();
+        answers.record(1, 1, Recorded::from(&Answer::from(54940u32)));
+        answers.record(1, 2, Recorded::hashed(&Answer::from(54208u32)));
+        let parsed = Answers::parse(&answers.to_string()).unwrap();
+        assert_eq!(answers, parsed);
+
+        assert!(parsed.get(1, 1).unwrap().matches(&Answer::from(54940u64)));
+        assert!(parsed.get(1, 2).unwrap().matches(&Answer::from(54208u64)));
+        assert!(!parsed.get(1, 2).unwrap().matches(&Answer::from(54209u64)));
+        assert_eq!(None, parsed.get(2, 1));
+    }
+
+    #[test]
+    fn fnv1a_known_values() {
+        assert_eq!(0xcbf29ce484222325, fnv1a(""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a("a"));
+    }
+}
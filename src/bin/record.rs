@@ -0,0 +1,94 @@
+//! Records the answers the real inputs give into `input/YEAR/answers.txt`, which the answers
+//! regression test checks against. Only run this once a changed answer is known to be right.
+//!
+//! Usage: `cargo run --release --bin record [YEAR] [DAY [PART]] [--hash]`, where `--hash` stores
+//! the answers as hashes rather than in plain text.
+
+use std::{fs, process::ExitCode};
+
+use aoc::{
+    answers::{Answers, Recorded},
+    solution::{FIRST_YEAR, YEARS},
+    trace,
+};
+
+fn main() -> ExitCode {
+    trace::init();
+    let mut hash = false;
+    let mut year = None;
+    let mut selected = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.parse::<u16>() {
+            _ if arg == "--hash" => hash = true,
+            Ok(n) if n >= FIRST_YEAR && year.is_none() && selected.is_empty() => year = Some(n),
+            Ok(n) if n <= u8::MAX.into() && selected.len() < 2 => selected.push(n as u8),
+            _ => {
+                eprintln!("usage: record [YEAR] [DAY [PART]] [--hash]");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let (day, part) = (selected.first().copied(), selected.get(1).copied());
+
+    for days in YEARS
+        .iter()
+        .filter(|days| year.is_none_or(|y| y == days[0].year))
+    {
+        let path = Answers::path(days[0].year);
+        let mut answers = match Answers::load(&path) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("can't read {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for solution in days.iter().filter(|d| day.is_none_or(|day| day == d.day)) {
+            let input_path = solution.input_path();
+            let input = match fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!(
+                        "{} day {}: can't read {}: {err}",
+                        solution.year,
+                        solution.day,
+                        input_path.display()
+                    );
+                    continue;
+                }
+            };
+            let parsed = match (solution.parse)(input.trim_end_matches('\n')) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            for p in [1, 2]
+                .into_iter()
+                .filter(|&p| part.is_none_or(|part| part == p))
+            {
+                let answer = parsed.solve(p);
+                let recorded = if hash {
+                    Recorded::hashed(&answer)
+                } else {
+                    Recorded::from(&answer)
+                };
+                let name = format!("{} day {} part {p}", solution.year, solution.day);
+                match answers.get(solution.day, p) {
+                    Some(old) if old.matches(&answer) => {}
+                    Some(old) => println!("{name}: {old} -> {answer}"),
+                    None => println!("{name}: {answer} (new)"),
+                }
+                answers.record(solution.day, p, recorded);
+            }
+        }
+
+        if let Err(err) = answers.save(&path) {
+            eprintln!("can't write {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,278 @@
+//! Runs solutions without cargo-aoc, timing each generator and part.
+//!
+//! Usage: `cargo run --release --bin run [YEAR] [DAY [PART]] [--input FILE] [--json] [--explain]`,
+//! where
+//! `FILE` can be `-` for stdin. Runs every solved part of every year when neither is given,
+//! reading `input/YEAR/dayN.txt` unless an input file is given, and skipping days without
+//! an input unless the day is named.
+//!
+//! `--json` prints a JSON object per line for each part instead, with its answer or error,
+//! timings in nanoseconds, the input path and any findings from the day's validator.
+//!
+//! `--explain` also shows how each answer came about, for days that can say, like which seed
+//! wins day 5 of 2023 and the path it takes through the maps. It isn't timed.
+
+use std::{
+    fs,
+    io::{self, Read},
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answer::Answer,
+    solution::{self, Day, FIRST_YEAR},
+    trace, validate,
+};
+use serde::Serialize;
+
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    json: bool,
+    explain: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        year: None,
+        day: None,
+        part: None,
+        input: None,
+        json: false,
+        explain: false,
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--input" || arg == "-i" {
+            args.input = Some(iter.next().ok_or("--input needs a file or -")?);
+        } else if arg == "--json" {
+            args.json = true;
+        } else if arg == "--explain" {
+            args.explain = true;
+        } else if let Some(year) = arg.parse().ok().filter(|&y| y >= FIRST_YEAR) {
+            if args.year.is_some() || args.day.is_some() {
+                return Err(arg);
+            }
+            args.year = Some(year);
+        } else if args.day.is_none() {
+            let day = arg.trim_start_matches("day").parse().map_err(|_| arg)?;
+            args.day = Some(day);
+        } else if args.part.is_none() {
+            let part = arg.trim_start_matches("part").parse().map_err(|_| arg)?;
+            args.part = Some(part);
+        } else {
+            return Err(arg);
+        }
+    }
+    Ok(args)
+}
+
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        Ok(buf)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+fn main() -> ExitCode {
+    trace::init();
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(arg) => {
+            eprintln!("unexpected argument {arg:?}");
+            eprintln!("usage: run [YEAR] [DAY [PART]] [--input FILE] [--json] [--explain]");
+            return ExitCode::FAILURE;
+        }
+    };
+    let days = solution::days()
+        .filter(|d| args.year.is_none_or(|year| year == d.year))
+        .filter(|d| args.day.is_none_or(|day| day == d.day))
+        .filter(|d| args.day.is_some() || args.input.is_some() || d.input_path().exists())
+        .collect::<Vec<_>>();
+    if args.input.is_some() && days.len() > 1 {
+        eprintln!("--input needs a single day to run it with");
+        return ExitCode::FAILURE;
+    }
+    let parts = [1, 2]
+        .into_iter()
+        .filter(|&part| args.part.is_none_or(|p| p == part))
+        .collect::<Vec<_>>();
+    if days.is_empty() || parts.is_empty() {
+        eprintln!("nothing to run");
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for day in days {
+        let path = match &args.input {
+            Some(path) => path.clone(),
+            None => day.input_path().display().to_string(),
+        };
+        let input = read_input(&path);
+        let input = input.as_deref().map(|input| input.trim_end_matches('\n'));
+        let ran = match input {
+            Ok(input) => run(day, &parts, input, args.explain),
+            Err(err) => Ran {
+                generator: Err(format!("can't read {path}: {err}")),
+                parts: Vec::new(),
+            },
+        };
+        failed |= ran.failed();
+        if args.json {
+            let findings = input
+                .ok()
+                .and_then(|input| validate::validate(day.year, day.day, input))
+                .unwrap_or_default();
+            ran.print_json(day, &parts, &path, &findings);
+        } else {
+            ran.print(day);
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// A part's answer, how long it took and any explanation asked for, or why there isn't one.
+type Solved = Result<(Answer, Duration, Option<String>), String>;
+
+/// What happened to a day's generator and each part run from its output.
+struct Ran {
+    /// How long the generator took, or why there's nothing to solve.
+    generator: Result<Duration, String>,
+    parts: Vec<(u8, Solved)>,
+}
+
+impl Ran {
+    fn failed(&self) -> bool {
+        self.generator.is_err() || self.parts.iter().any(|(_, result)| result.is_err())
+    }
+
+    fn print(&self, day: &Day) {
+        let gen_time = match &self.generator {
+            Ok(time) => time,
+            Err(err) => {
+                eprintln!("{} day {}: {err}", day.year, day.day);
+                return;
+            }
+        };
+        for (part, result) in &self.parts {
+            match result {
+                Ok((answer, time, explanation)) => {
+                    println!("{} day {} part {part}: {answer}", day.year, day.day);
+                    println!("\tgenerator: {gen_time:?}, runner: {time:?}");
+                    for line in explanation.iter().flat_map(|e| e.lines()) {
+                        println!("\t{line}");
+                    }
+                }
+                Err(err) => eprintln!("{} day {} part {part}: {err}", day.year, day.day),
+            }
+        }
+    }
+
+    /// Prints a [`Report`] for each of `parts`, even those that never ran because the
+    /// generator failed.
+    fn print_json(&self, day: &Day, parts: &[u8], input: &str, findings: &[validate::Finding]) {
+        let warnings = findings
+            .iter()
+            .map(|finding| Warning {
+                line: finding.line,
+                message: &finding.message,
+            })
+            .collect::<Vec<_>>();
+        for &part in parts {
+            let result = self.parts.iter().find(|(p, _)| *p == part).map(|(_, r)| r);
+            let solved = result.and_then(|r| r.as_ref().ok());
+            let error = self.generator.as_ref().err();
+            let report = Report {
+                year: day.year,
+                day: day.day,
+                part,
+                input,
+                answer: solved.map(|(answer, _, _)| answer.to_string()),
+                error: error.or(result.and_then(|r| r.as_ref().err())),
+                generator_ns: self.generator.as_ref().ok().map(nanos),
+                solve_ns: solved.map(|(_, time, _)| nanos(time)),
+                explanation: solved.and_then(|(_, _, explanation)| explanation.as_deref()),
+                warnings: &warnings,
+            };
+            println!(
+                "{}",
+                serde_json::to_string(&report).expect("reports always serialize")
+            );
+        }
+    }
+}
+
+/// One line of `--json` output.
+#[derive(Serialize)]
+struct Report<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &'a str,
+    answer: Option<String>,
+    error: Option<&'a String>,
+    generator_ns: Option<u64>,
+    solve_ns: Option<u64>,
+    explanation: Option<&'a str>,
+    warnings: &'a [Warning<'a>],
+}
+
+#[derive(Serialize)]
+struct Warning<'a> {
+    line: usize,
+    message: &'a str,
+}
+
+fn nanos(time: &Duration) -> u64 {
+    time.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Parses the input once and runs each of `parts` on it, timing each and catching failures,
+/// and explaining each answer if asked to.
+fn run(day: &Day, parts: &[u8], input: &str, explain: bool) -> Ran {
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(|| (day.parse)(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => {
+            return Ran {
+                generator: Err(format!("generator failed: {err}")),
+                parts: Vec::new(),
+            }
+        }
+        Err(_) => {
+            return Ran {
+                generator: Err("generator panicked".to_string()),
+                parts: Vec::new(),
+            }
+        }
+    };
+    let generator = Ok(start.elapsed());
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                let answer = parsed.solve(part);
+                let time = start.elapsed();
+                let explanation = explain.then(|| parsed.explain(part)).flatten();
+                (answer, time, explanation)
+            }))
+            .map_err(|_| "panicked".to_string());
+            (part, result)
+        })
+        .collect();
+    Ran { generator, parts }
+}
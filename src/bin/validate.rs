@@ -0,0 +1,71 @@
+//! Checks puzzle inputs against what each day's solvers assume, without solving anything.
+//!
+//! Usage: `cargo run --bin validate [YEAR] [day...]`, defaulting to every day with an input in
+//! `input/YEAR`, for every solved year unless one is given.
+
+use std::{fs, process::ExitCode};
+
+use aoc::{
+    solution::{self, FIRST_YEAR, YEARS},
+    trace,
+};
+
+fn main() -> ExitCode {
+    trace::init();
+    let mut args = std::env::args().skip(1).peekable();
+    let year = args
+        .peek()
+        .and_then(|arg| arg.parse::<u16>().ok())
+        .filter(|&year| year >= FIRST_YEAR);
+    if year.is_some() {
+        args.next();
+    }
+    let days = args
+        .map(|arg| arg.trim_start_matches("day").parse::<u8>())
+        .collect::<Result<Vec<_>, _>>();
+    let (days, explicit) = match days {
+        Ok(days) if days.is_empty() => ((1..=25).collect(), false),
+        Ok(days) => (days, true),
+        Err(err) => {
+            eprintln!("usage: validate [YEAR] [day...]: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let years = match year {
+        Some(year) => vec![year],
+        None => YEARS.iter().map(|days| days[0].year).collect(),
+    };
+
+    let mut failed = false;
+    for (year, day) in years
+        .iter()
+        .flat_map(|&y| days.iter().map(move |&d| (y, d)))
+    {
+        let path = solution::input_dir(year).join(format!("day{day}.txt"));
+        let Ok(input) = fs::read_to_string(&path) else {
+            if explicit {
+                println!("{year} day {day}: can't read {}", path.display());
+                failed = true;
+            }
+            continue;
+        };
+        let input = input.trim_end_matches('\n');
+        let Some(findings) = aoc::validate::validate(year, day, input) else {
+            println!("{year} day {day}: no validator");
+            continue;
+        };
+        if findings.is_empty() {
+            println!("{year} day {day}: ok");
+        }
+        for finding in &findings {
+            println!("{finding}");
+        }
+        failed |= !findings.is_empty();
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
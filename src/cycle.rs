@@ -0,0 +1,171 @@
+use std::hash::Hash;
+
+use indexmap::IndexSet;
+
+use crate::trace::debug;
+
+/// The shape of the sequence `initial, step(initial), step(step(initial)), ...`: after
+/// `tail_len` steps it enters a loop of `cycle_len` states that repeats forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    pub tail_len: usize,
+    pub cycle_len: usize,
+    /// The first `tail_len + cycle_len` states, starting with `initial`.
+    states: Vec<T>,
+}
+
+impl<T> Cycle<T> {
+    /// The step before the end of the first loop that has the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.tail_len {
+            n
+        } else {
+            self.tail_len + (n - self.tail_len) % self.cycle_len
+        }
+    }
+
+    pub fn state_at(&self, n: usize) -> &T {
+        &self.states[self.equivalent_step(n)]
+    }
+
+    pub fn states(&self) -> &[T] {
+        &self.states
+    }
+}
+
+/// Finds the cycle by remembering every state seen. Needs `T: Hash` and memory for each state
+/// up to the first repeat.
+pub fn find_cycle<T, F>(initial: T, mut step: F) -> Cycle<T>
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = IndexSet::new();
+    let mut current = initial;
+    loop {
+        let next = step(&current);
+        let (index, inserted) = seen.insert_full(current);
+        if !inserted {
+            debug!(
+                tail_len = index,
+                cycle_len = seen.len() - index,
+                "states repeat"
+            );
+            return Cycle {
+                tail_len: index,
+                cycle_len: seen.len() - index,
+                states: seen.into_iter().collect(),
+            };
+        }
+        current = next;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only compares states and keeps two of them
+/// around while searching.
+pub fn find_cycle_brent<T, F>(initial: T, mut step: F) -> Cycle<T>
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // find the cycle length by racing a hare ahead of a tortoise that teleports to it at each
+    // power of two
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    // then walk two pointers `cycle_len` apart from the start until they meet
+    let mut states = vec![initial];
+    for i in 0..cycle_len {
+        let next = step(&states[i]);
+        states.push(next);
+    }
+    let mut tail_len = 0;
+    while states[tail_len] != states[tail_len + cycle_len] {
+        let next = step(&states[tail_len + cycle_len]);
+        states.push(next);
+        tail_len += 1;
+    }
+    states.truncate(tail_len + cycle_len);
+    debug!(tail_len, cycle_len, "states repeat");
+
+    Cycle {
+        tail_len,
+        cycle_len,
+        states,
+    }
+}
+
+/// The state after `n` applications of `step`, skipping whole loops once the states repeat.
+pub fn fast_forward<T, F>(initial: T, mut step: F, n: usize) -> T
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = IndexSet::new();
+    let mut current = initial;
+    for i in 0..n {
+        let next = step(&current);
+        let (index, inserted) = seen.insert_full(current);
+        if !inserted {
+            debug!(tail_len = index, cycle_len = i - index, "states repeat");
+            let cycle = Cycle {
+                tail_len: index,
+                cycle_len: i - index,
+                states: seen.into_iter().collect(),
+            };
+            return cycle.state_at(n).clone();
+        }
+        current = next;
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ...
+    fn collatz(n: &u64) -> u64 {
+        if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        }
+    }
+
+    #[test]
+    fn hash_and_brent_agree() {
+        let cycle = find_cycle(3, collatz);
+        assert_eq!((5, 3), (cycle.tail_len, cycle.cycle_len));
+        assert_eq!(&[3, 10, 5, 16, 8, 4, 2, 1], cycle.states());
+        assert_eq!(cycle, find_cycle_brent(3, collatz));
+    }
+
+    #[test]
+    fn state_at_any_step() {
+        let cycle = find_cycle(3, collatz);
+        assert_eq!(&16, cycle.state_at(3));
+        assert_eq!(&4, cycle.state_at(8));
+        assert_eq!(&1, cycle.state_at(1_000_000_000));
+        assert_eq!(1, fast_forward(3, collatz, 1_000_000_000));
+        assert_eq!(16, fast_forward(3, collatz, 3));
+    }
+
+    #[test]
+    fn pure_cycle() {
+        let cycle = find_cycle_brent(0, |n| (n + 1) % 7);
+        assert_eq!((0, 7), (cycle.tail_len, cycle.cycle_len));
+        assert_eq!(&2, cycle.state_at(30));
+    }
+}
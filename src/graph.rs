@@ -0,0 +1,294 @@
+use std::{
+    collections::{BTreeMap, BinaryHeap, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+use indexmap::IndexSet;
+
+use crate::trace::trace;
+
+/// Nodes joined by edges with lengths, such as a maze compressed to its junctions and the
+/// corridors between them.
+///
+/// Displays as a Graphviz digraph, e.g. for `dot -Tsvg`.
+#[derive(Debug, Clone)]
+pub struct Graph<S> {
+    nodes: IndexSet<S>,
+    /// for each node, the nodes a corridor leads to and its length in steps
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<S: Copy + Eq + Hash> Graph<S> {
+    /// Compresses everything reachable from `start` by `successors`.
+    ///
+    /// The nodes are `start`, `keep`, every state with more than two successors, and wherever a
+    /// corridor forks. Corridors are followed without turning back, so one that only goes one
+    /// way, or dead-ends, only gives the edges it can be walked along.
+    pub fn compress<FN, IN>(start: S, keep: &[S], mut successors: FN) -> Self
+    where
+        FN: FnMut(&S) -> IN,
+        IN: IntoIterator<Item = S>,
+    {
+        let mut next = |s: &S| successors(s).into_iter().collect::<Vec<_>>();
+        let mut graph = Graph {
+            nodes: IndexSet::from([start]),
+            edges: vec![Vec::new()],
+        };
+        let mut queue = VecDeque::from([0]);
+        while let Some(from) = queue.pop_front() {
+            let junction = graph.nodes[from];
+            for first in next(&junction) {
+                let (mut prev, mut current, mut len) = (junction, first, 1);
+                let end = loop {
+                    let ways = next(&current);
+                    let onward = ways.iter().filter(|&&s| s != prev).collect::<Vec<_>>();
+                    if current == start
+                        || keep.contains(&current)
+                        || ways.len() > 2
+                        || onward.len() > 1
+                    {
+                        break Some(current);
+                    }
+                    let Some(&&step) = onward.first() else {
+                        break None;
+                    };
+                    (prev, current, len) = (current, step, len + 1);
+                };
+                let Some(end) = end else {
+                    continue;
+                };
+                let (to, added) = graph.nodes.insert_full(end);
+                if added {
+                    graph.edges.push(Vec::new());
+                    queue.push_back(to);
+                }
+                graph.edges[from].push((to, len));
+            }
+        }
+        graph
+    }
+}
+
+impl<S: Eq + Hash> Graph<S> {
+    /// A graph with an edge of length 1 each way between every pair in `edges`.
+    pub fn undirected(edges: impl IntoIterator<Item = (S, S)>) -> Self {
+        let mut graph = Graph {
+            nodes: IndexSet::new(),
+            edges: Vec::new(),
+        };
+        for (a, b) in edges {
+            let [a, b] = [a, b].map(|node| {
+                let (index, added) = graph.nodes.insert_full(node);
+                if added {
+                    graph.edges.push(Vec::new());
+                }
+                index
+            });
+            graph.edges[a].push((b, 1));
+            graph.edges[b].push((a, 1));
+        }
+        graph
+    }
+
+    pub fn index_of(&self, node: &S) -> Option<usize> {
+        self.nodes.get_index_of(node)
+    }
+}
+
+impl<S> Graph<S> {
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, index: usize) -> &S {
+        &self.nodes[index]
+    }
+
+    /// The nodes `index` leads straight to, with the number of steps to each.
+    pub fn edges(&self, index: usize) -> &[(usize, usize)] {
+        &self.edges[index]
+    }
+
+    /// The most steps from node `from` to node `to` without visiting any node twice, or `None`
+    /// if `to` can't be reached.
+    pub fn longest_path(&self, from: usize, to: usize) -> Option<usize> {
+        assert!(
+            self.len() <= 64,
+            "{} nodes is too many to search",
+            self.len()
+        );
+        // anything that reaches the only node next to `to` has to go on to `to`, or it never can
+        let into_to = (0..self.len())
+            .filter(|&n| self.edges[n].iter().any(|&(m, _)| m == to))
+            .collect::<Vec<_>>();
+        let (to, extra) = match into_to.as_slice() {
+            &[last] if last != from => {
+                let (_, len) = self.edges[last].iter().find(|&&(m, _)| m == to).unwrap();
+                (last, *len)
+            }
+            _ => (to, 0),
+        };
+        self.dfs(from, to, 1 << from).map(|len| len + extra)
+    }
+
+    /// The smallest total length of edges that can be cut to split the graph in two, with the
+    /// nodes on one side, or `None` if there's only one node.
+    ///
+    /// Stoer-Wagner, which needs every edge to have a match going back the other way.
+    pub fn min_cut(&self) -> Option<(usize, Vec<usize>)> {
+        let n = self.len();
+        // BTreeMaps so ties are broken the same way every time
+        let mut weights = vec![BTreeMap::new(); n];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, len) in edges.iter().filter(|&&(to, _)| to != from) {
+                *weights[from].entry(to).or_insert(0) += len;
+            }
+        }
+        // the original nodes merged into each remaining one
+        let mut merged = (0..n).map(|i| vec![i]).collect::<Vec<_>>();
+        let mut remaining = (0..n).collect::<Vec<_>>();
+        let mut best: Option<(usize, Vec<usize>)> = None;
+
+        while remaining.len() > 1 {
+            // add the most tightly connected node until there are none left, then the last two
+            // added are split by exactly the edges into the last
+            let mut added = vec![false; n];
+            let mut connection = vec![0; n];
+            let mut queue = BinaryHeap::from([(0, remaining[0])]);
+            let (mut prev, mut last, mut cut, mut count) = (remaining[0], remaining[0], 0, 0);
+            while let Some((weight, node)) = queue.pop() {
+                if added[node] || weight != connection[node] {
+                    continue;
+                }
+                added[node] = true;
+                (prev, last, cut, count) = (last, node, weight, count + 1);
+                for (&other, &w) in &weights[node] {
+                    if !added[other] {
+                        connection[other] += w;
+                        queue.push((connection[other], other));
+                    }
+                }
+            }
+            if count < remaining.len() {
+                // nothing joins what was added to the rest
+                let side = remaining.iter().filter(|&&r| added[r]);
+                return Some((0, side.flat_map(|&r| merged[r].clone()).collect()));
+            }
+            trace!(cut, remaining = remaining.len(), "cut of the phase");
+            if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+                best = Some((cut, merged[last].clone()));
+            }
+
+            let moved = std::mem::take(&mut merged[last]);
+            merged[prev].extend(moved);
+            for (other, w) in std::mem::take(&mut weights[last]) {
+                weights[other].remove(&last);
+                if other != prev {
+                    *weights[prev].entry(other).or_insert(0) += w;
+                    *weights[other].entry(prev).or_insert(0) += w;
+                }
+            }
+            remaining.retain(|&r| r != last);
+        }
+        best
+    }
+
+    fn dfs(&self, at: usize, to: usize, seen: u64) -> Option<usize> {
+        if at == to {
+            return Some(0);
+        }
+        self.edges[at]
+            .iter()
+            .filter(|&&(next, _)| seen & (1 << next) == 0)
+            .filter_map(|&(next, len)| Some(len + self.dfs(next, to, seen | 1 << next)?))
+            .max()
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Graph<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        for (i, node) in self.nodes.iter().enumerate() {
+            writeln!(f, "    {i} [label=\"{node}\"];")?;
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, len) in edges {
+                writeln!(f, "    {from} -> {to} [label={len}];")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Point, grid::Grid};
+
+    const MAZE: &str = "\
+.....
+.#.#.
+.....";
+
+    #[test]
+    fn compresses_and_renders() {
+        let grid = Grid::parse_with(MAZE, |c| c == '.');
+        let end = Point::new(4, 2);
+        let graph = Graph::compress(Point::ORIGIN, &[end], |&p| {
+            grid.neighbors4(p).filter(|&n| grid[n]).collect::<Vec<_>>()
+        });
+
+        // the start, the end, and the two junctions in the middle column
+        assert_eq!(4, graph.len());
+        let [start, top, bottom, end] = [Point::ORIGIN, Point::new(2, 0), Point::new(2, 2), end]
+            .map(|p| graph.index_of(&p).unwrap());
+        let mut from_top = graph.edges(top).to_vec();
+        from_top.sort();
+        let mut expected = vec![(start, 2), (bottom, 2), (end, 4)];
+        expected.sort();
+        assert_eq!(expected, from_top);
+
+        // down and round the bottom, back up the middle, then round the top
+        assert_eq!(Some(10), graph.longest_path(start, end));
+        let dot = graph.to_string();
+        assert!(dot.starts_with("digraph {\n    0 [label=\"(0, 0)\"];\n"));
+        assert!(dot.contains(&format!("    {top} -> {end} [label=4];\n")));
+    }
+
+    #[test]
+    fn follows_one_way_corridors() {
+        // only right or down, so the corridors fork rather than meet at junctions, and never
+        // lead back
+        let grid = Grid::parse_with(MAZE, |c| c == '.');
+        let end = Point::new(4, 2);
+        let graph = Graph::compress(Point::ORIGIN, &[end], |&p| {
+            [Point::new(p.x + 1, p.y), Point::new(p.x, p.y + 1)]
+                .into_iter()
+                .filter(|&n| grid.get(n) == Some(&true))
+                .collect::<Vec<_>>()
+        });
+        let [start, end] = [Point::ORIGIN, end].map(|p| graph.index_of(&p).unwrap());
+        assert_eq!(Some(6), graph.longest_path(start, end));
+        assert_eq!(None, graph.longest_path(end, start));
+    }
+
+    #[test]
+    fn min_cut_of_two_triangles() {
+        // two triangles joined by a single edge, and a node on its own
+        let mut graph = Graph::undirected([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 6), (6, 4)]);
+        let (cut, mut side) = graph.min_cut().unwrap();
+        side.sort();
+        assert_eq!(1, cut);
+        assert!(side == [0, 1, 2] || side == [3, 4, 5], "{side:?}");
+
+        graph.nodes.insert(7);
+        graph.edges.push(Vec::new());
+        assert_eq!(0, graph.min_cut().unwrap().0);
+        assert_eq!(None, Graph::<u8>::undirected([]).min_cut());
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Point},
+    parse::{ParseError, Source},
+};
+
+/// A rectangular grid stored row-major in a single `Vec`, addressed by [`Point`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same length"
+        );
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Self
+    where
+        T: From<char>,
+    {
+        Self::parse_with(input, T::from)
+    }
+
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut f).collect())
+                .collect(),
+        )
+    }
+
+    pub fn try_parse(src: &Source<'_>, expected: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::try_parse_with(src, expected, |c| T::try_from(c).ok())
+    }
+
+    /// Parses a grid from the whole of `src`, failing on ragged rows or any char that `f`
+    /// rejects.
+    pub fn try_parse_with(
+        src: &Source<'_>,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::try_parse_lines(src, src.lines(), expected, f)
+    }
+
+    /// Like [`Grid::try_parse_with`] for some of the lines of `src`, e.g. one block of several.
+    pub fn try_parse_lines<'a>(
+        src: &Source<'a>,
+        lines: impl IntoIterator<Item = &'a str>,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut rows = Vec::new();
+        for line in lines {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| f(c).ok_or_else(|| src.error(&line[i..], expected)))
+                .collect::<Result<Vec<_>, _>>()?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                let at = line.char_indices().nth(width).map(|(i, _)| i);
+                return Err(src.error(
+                    &line[at.unwrap_or(line.len())..],
+                    format!("a row {width} wide"),
+                ));
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[self.idx(p)])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            let idx = self.idx(p);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Moves one step from `p`, returning `None` if that leaves the grid.
+    pub fn step(&self, p: Point, dir: Direction) -> Option<Point> {
+        let next = p.step(dir);
+        self.contains(next).then_some(next)
+    }
+
+    /// In-bounds orthogonal neighbours, clockwise from north.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|n| self.contains(*n))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours, clockwise from north.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .flat_map(move |dir| [p.step(dir), p.step(dir).step(dir.turn_right())])
+            .filter(|n| self.contains(*n))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + '_ {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.cols().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.cols().flat_map(|col| col.rev()).cloned().collect(),
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.cols().rev().flatten().cloned().collect(),
+        }
+    }
+
+    fn idx(&self, p: Point) -> usize {
+        p.y as usize * self.width + p.x as usize
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p).unwrap_or_else(|| panic!("{p} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p} out of bounds"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc
+def";
+
+    #[test]
+    fn parse_and_index() {
+        let grid: Grid<char> = Grid::parse(EXAMPLE);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!('a', grid[Point::new(0, 0)]);
+        assert_eq!('f', grid[Point::new(2, 1)]);
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(vec![&'b', &'e'], grid.col(1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid: Grid<char> = Grid::parse(EXAMPLE);
+        assert_eq!(
+            vec![Point::new(2, 0), Point::new(1, 1), Point::new(0, 0)],
+            grid.neighbors4(Point::new(1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(0, 1),
+                Point::new(0, 0)
+            ],
+            grid.neighbors8(Point::new(1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.step(Point::new(2, 1), Direction::Right));
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid: Grid<char> = Grid::parse(EXAMPLE);
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_ccw().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+    }
+
+    #[test]
+    fn try_parse_reports_bad_cells() {
+        let src = Source::new(17, "12\n34");
+        let grid = Grid::try_parse_with(&src, "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(4, grid[Point::new(1, 1)]);
+
+        let src = Source::new(17, "12\n3x");
+        let err = Grid::try_parse_with(&src, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.text.as_str()));
+
+        let src = Source::new(17, "12\n345");
+        let err = Grid::try_parse_with(&src, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((2, 3, "5"), (err.line, err.column, err.text.as_str()));
+    }
+}
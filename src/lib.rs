@@ -1,24 +1,16 @@
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day8;
-mod day9;
+pub mod answer;
+pub mod answers;
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod trace;
+pub mod validate;
+pub mod y2023;
 use aoc_runner_derive::aoc_lib;
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-
+// cargo-aoc only knows about one year per crate.
 aoc_lib! { year = 2023 }
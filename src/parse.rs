@@ -0,0 +1,161 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A generator failure pointing at the offending text in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    /// The rest of the line from the point of failure.
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// What was expected and what was found instead, without the location.
+    pub fn problem(&self) -> String {
+        if self.text.is_empty() {
+            format!("expected {}, found end of line", self.expected)
+        } else {
+            format!("expected {}, found {:?}", self.expected, self.text)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day,
+            self.line,
+            self.column,
+            self.problem()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A day's whole puzzle input, used to turn any slice of it into a [`ParseError`] that knows
+/// where that slice came from.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.input.lines()
+    }
+
+    /// An empty slice at the very end of the input, for reporting truncated input.
+    pub fn end(&self) -> &'a str {
+        &self.input[self.input.len()..]
+    }
+
+    /// The 1-based line and column of the start of `at`, which must be a slice of this
+    /// source's input.
+    pub fn locate(&self, at: &str) -> (usize, usize) {
+        let before = &self.input[..self.offset(at)];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error at the start of `at`, which must be a slice of this source's input.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.locate(at);
+        let rest = &self.input[self.offset(at)..];
+
+        ParseError {
+            day: self.day,
+            line,
+            column,
+            text: rest.lines().next().unwrap_or("").to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    fn offset(&self, at: &str) -> usize {
+        (at.as_ptr() as usize)
+            .checked_sub(self.input.as_ptr() as usize)
+            .filter(|offset| *offset <= self.input.len())
+            .expect("location should be a slice of the input")
+    }
+
+    /// The next item from `iter`, or an error at the end of the input if it ran out.
+    pub fn next<I>(&self, iter: &mut I, expected: &str) -> Result<&'a str, ParseError>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        iter.next().ok_or_else(|| self.error(self.end(), expected))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("{delimiter:?}")))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("{prefix:?}")))
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &'a str, expected: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_errors() {
+        let input = "1 2\n3 x 5";
+        let src = Source::new(9, input);
+        let line = input.lines().nth(1).unwrap();
+        let bad = line.split_whitespace().nth(1).unwrap();
+
+        let err = src.parse::<i64>(bad, "a number").unwrap_err();
+        assert_eq!((9, 2, 3), (err.day, err.line, err.column));
+        assert_eq!("x 5", err.text);
+        assert_eq!(
+            "day 9, line 2, column 3: expected a number, found \"x 5\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn truncated_input() {
+        let input = "1 2\n";
+        let src = Source::new(1, input);
+        let mut lines = src.lines();
+        assert_eq!(Ok("1 2"), src.next(&mut lines, "a line"));
+        let err = src.next(&mut lines, "a line").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert!(err.to_string().ends_with("found end of line"));
+    }
+}
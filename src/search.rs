@@ -0,0 +1,271 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use indexmap::{map::Entry, IndexMap};
+
+use crate::trace::debug;
+
+/// Visited states, each with the index of the state it was reached from and its best known cost.
+type Visited<S, C> = IndexMap<S, (usize, C)>;
+
+const NO_PARENT: usize = usize::MAX;
+
+/// Cheapest path from `start` to the first state satisfying `goal`, or `None` if there isn't one.
+///
+/// Returns the total cost and every state along the path, including both ends.
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, successors: FN, goal: FG) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, goal, |_| C::default())
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate the remaining cost.
+pub fn astar<S, C, FN, IN, FG, FH>(
+    start: S,
+    mut successors: FN,
+    mut goal: FG,
+    mut heuristic: FH,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+    FH: FnMut(&S) -> C,
+{
+    let mut heap = BinaryHeap::new();
+    heap.push(Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    });
+    let mut visited: Visited<S, C> = IndexMap::new();
+    visited.insert(start, (NO_PARENT, C::default()));
+
+    while let Some(Candidate { cost, index, .. }) = heap.pop() {
+        let (state, &(_, best)) = visited.get_index(index).expect("visited");
+        if cost > best {
+            // a cheaper way here was queued after this one
+            continue;
+        }
+        if goal(state) {
+            debug!(
+                visited = visited.len(),
+                queued = heap.len(),
+                "reached the goal"
+            );
+            return Some((cost, reconstruct(&visited, index)));
+        }
+
+        for (next, step) in successors(&state.clone()) {
+            let cost = cost + step;
+            let next_index = match visited.entry(next) {
+                Entry::Vacant(e) => {
+                    let next_index = e.index();
+                    e.insert((index, cost));
+                    next_index
+                }
+                Entry::Occupied(mut e) => {
+                    if e.get().1 <= cost {
+                        continue;
+                    }
+                    e.insert((index, cost));
+                    e.index()
+                }
+            };
+            let (next, _) = visited.get_index(next_index).expect("just inserted");
+            heap.push(Candidate {
+                estimate: cost + heuristic(next),
+                cost,
+                index: next_index,
+            });
+        }
+    }
+    debug!(visited = visited.len(), "no way to the goal");
+    None
+}
+
+/// Shortest path by number of steps from `start` to the first state satisfying `goal`.
+pub fn bfs<S, FN, IN, FG>(start: S, successors: FN, goal: FG) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let (visited, found) = breadth_first(start, usize::MAX, successors, goal);
+    let index = found?;
+    Some((visited[index].1, reconstruct(&visited, index)))
+}
+
+/// Every state reachable from `start`, in the order visited, with its distance in steps.
+pub fn bfs_reachable<S, FN, IN>(start: S, successors: FN) -> IndexMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+{
+    bfs_within(start, usize::MAX, successors)
+}
+
+/// Like [`bfs_reachable`], but only the states at most `limit` steps from `start`.
+pub fn bfs_within<S, FN, IN>(start: S, limit: usize, successors: FN) -> IndexMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+{
+    let (visited, _) = breadth_first(start, limit, successors, |_| false);
+    visited
+        .into_iter()
+        .map(|(state, (_, dist))| (state, dist))
+        .collect()
+}
+
+fn breadth_first<S, FN, IN, FG>(
+    start: S,
+    limit: usize,
+    mut successors: FN,
+    mut goal: FG,
+) -> (Visited<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut visited: Visited<S, usize> = IndexMap::new();
+    visited.insert(start, (NO_PARENT, 0));
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let (state, &(_, dist)) = visited.get_index(index).expect("visited");
+        if goal(state) {
+            debug!(
+                visited = visited.len(),
+                queued = queue.len(),
+                "reached the goal"
+            );
+            return (visited, Some(index));
+        }
+        if dist == limit {
+            continue;
+        }
+        for next in successors(&state.clone()) {
+            if let Entry::Vacant(e) = visited.entry(next) {
+                queue.push_back(e.index());
+                e.insert((index, dist + 1));
+            }
+        }
+    }
+    debug!(visited = visited.len(), "searched everything in reach");
+    (visited, None)
+}
+
+fn reconstruct<S: Clone, C>(visited: &Visited<S, C>, mut index: usize) -> Vec<S> {
+    let mut path = Vec::new();
+    while index != NO_PARENT {
+        let (state, &(parent, _)) = visited.get_index(index).expect("visited");
+        path.push(state.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Heap entry ordered so the `BinaryHeap` pops the lowest estimate first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Point, grid::Grid};
+
+    const MAZE: &str = "1191
+1911
+1119";
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid = Grid::parse_with(MAZE, |c| c.to_digit(10).unwrap());
+        let goal = Point::new(3, 2);
+        let successors = |p: &Point| {
+            grid.neighbors4(*p)
+                .map(|n| (n, grid[n]))
+                .collect::<Vec<_>>()
+        };
+
+        let (cost, path) = dijkstra(Point::ORIGIN, successors, |p| *p == goal).unwrap();
+        assert_eq!(13, cost);
+        assert_eq!(Some(&Point::ORIGIN), path.first());
+        assert_eq!(Some(&goal), path.last());
+
+        let (astar_cost, astar_path) = astar(
+            Point::ORIGIN,
+            successors,
+            |p| *p == goal,
+            |p| p.manhattan(goal) as u32,
+        )
+        .unwrap();
+        assert_eq!((cost, path.len()), (astar_cost, astar_path.len()));
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let grid = Grid::parse_with(MAZE, |c| c == '1');
+        let successors = |p: &Point| grid.neighbors4(*p).filter(|n| grid[*n]).collect::<Vec<_>>();
+
+        let (steps, path) = bfs(Point::ORIGIN, successors, |p| *p == Point::new(3, 1)).unwrap();
+        assert_eq!(6, steps);
+        assert_eq!(7, path.len());
+        assert_eq!(
+            None,
+            bfs(Point::ORIGIN, successors, |p| *p == Point::new(3, 2))
+        );
+
+        let reachable = bfs_reachable(Point::ORIGIN, successors);
+        assert_eq!(9, reachable.len());
+        assert_eq!(Some(&6), reachable.get(&Point::new(3, 1)));
+
+        let near = bfs_within(Point::ORIGIN, 3, successors);
+        assert_eq!(5, near.len());
+        assert_eq!(Some(&3), near.get(&Point::new(1, 2)));
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use crate::{answer::Answer, parse::ParseError, y2023};
+
+/// A day's puzzle: parse the input once, then solve either part from it.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input: 'static;
+
+    /// Other ways to solve either part, benchmarked alongside `part1` and `part2`.
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// How `part` arrives at its answer, for days that can say more than the answer itself.
+    fn explain(_input: &Self::Input, _part: u8) -> Option<String> {
+        None
+    }
+}
+
+/// Another implementation of one part of a [`Solution`], which should give the same answer.
+pub struct Alternative<I> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Answer,
+}
+
+/// A [`Solution`] with its types erased, so every day can be listed and run the same way.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    /// Where this day's real input is kept.
+    pub fn input_path(&self) -> PathBuf {
+        input_dir(self.year).join(format!("day{}.txt", self.day))
+    }
+
+    /// Parses `input` and solves `part`, which must be 1 or 2.
+    pub fn solve(&self, part: u8, input: &str) -> Result<Answer, ParseError> {
+        let parsed = (self.parse)(input)?;
+        Ok(parsed.solve(part))
+    }
+}
+
+/// A day's parsed input, ready to solve either part.
+pub trait Parsed {
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+
+    fn explain(&self, part: u8) -> Option<String>;
+
+    fn solve(&self, part: u8) -> Answer {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(year = S::YEAR, day = S::DAY),
+            ret(Display)
+        )
+    )]
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(year = S::YEAR, day = S::DAY),
+            ret(Display)
+        )
+    )]
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+
+    fn explain(&self, part: u8) -> Option<String> {
+        S::explain(&self.0, part)
+    }
+}
+
+#[cfg_attr(
+    feature = "trace",
+    tracing::instrument(
+        name = "generator",
+        level = "debug",
+        skip_all,
+        fields(year = S::YEAR, day = S::DAY),
+        err(Display)
+    )
+)]
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    Ok(Box::new(Input::<S>(S::parse(input)?)))
+}
+
+/// Every solved year's days, oldest year first.
+pub const YEARS: &[&[Day]] = &[y2023::DAYS];
+
+/// The first year of Advent of Code, so any smaller number given with a year is a day.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Every solved day of every year, in order.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    days().find(|d| d.year == year && d.day == day)
+}
+
+/// Where the inputs, recorded answers and examples for `year` are kept.
+pub fn input_dir(year: u16) -> PathBuf {
+    Path::new("input").join(year.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_in_order() {
+        for days in YEARS {
+            assert!(days.iter().all(|d| d.year == days[0].year));
+            assert!(days.windows(2).all(|w| w[0].day < w[1].day));
+        }
+        assert!(YEARS.windows(2).all(|w| w[0][0].year < w[1][0].year));
+        let days = y2023::DAYS.iter().map(|d| d.day).collect::<Vec<_>>();
+        assert_eq!((1..=25).collect::<Vec<_>>(), days);
+    }
+
+    #[test]
+    fn solves_through_the_registry() {
+        let day = find(2023, 1).unwrap();
+        assert_eq!(Path::new("input/2023/day1.txt"), day.input_path());
+        assert_eq!("50", day.solve(1, "1abc2\npqr3stu8vwx").unwrap());
+        assert_eq!(
+            Answer::Unsigned(11),
+            y2023::day1::Day1::part2(&y2023::day1::Day1::parse("one").unwrap())
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Diagnostics, compiled in with the `trace` feature and chosen at run time with `RUST_LOG`,
+//! e.g. `RUST_LOG=aoc::y2023::day14=trace`.
+//!
+//! Days log through the macros here rather than `tracing`'s, so without the feature they
+//! compile to nothing. Spans go on functions with
+//! `#[cfg_attr(feature = "trace", tracing::instrument(...))]`.
+
+#[cfg(feature = "trace")]
+pub(crate) use tracing::{debug, trace};
+
+#[cfg(not(feature = "trace"))]
+macro_rules! discard {
+    ($($tokens:tt)*) => {{}};
+}
+
+#[cfg(not(feature = "trace"))]
+pub(crate) use {discard as debug, discard as trace};
+
+/// Sends diagnostics to stderr, filtered by `RUST_LOG`. Does nothing without the feature.
+pub fn init() {
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use crate::{
+    parse::{ParseError, Source},
+    y2023,
+};
+
+/// Something about a puzzle input that would make a solver panic or quietly give a wrong answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    pub message: String,
+}
+
+impl Finding {
+    /// A finding at the start of `at`, which must be a slice of `src`'s input.
+    pub fn at(src: &Source<'_>, at: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: src.day(),
+            line: src.locate(at).0,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<ParseError> for Finding {
+    fn from(err: ParseError) -> Self {
+        Self {
+            day: err.day,
+            line: err.line,
+            message: format!("column {}: {}", err.column, err.problem()),
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}, line {}: {}", self.day, self.line, self.message)
+    }
+}
+
+/// Checks `input` against everything the solvers for `day` of `year` rely on, or `None` if
+/// there's no such day.
+pub fn validate(year: u16, day: u8, input: &str) -> Option<Vec<Finding>> {
+    match year {
+        y2023::YEAR => y2023::validate(day, input),
+        _ => None,
+    }
+}
+
+/// Runs `parse` on every item, keeping what parsed and a finding for each that didn't.
+pub(crate) fn parse_each<'a, T>(
+    items: impl IntoIterator<Item = &'a str>,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+    findings: &mut Vec<Finding>,
+) -> Vec<T> {
+    items
+        .into_iter()
+        .filter_map(|item| parse(item).map_err(|err| findings.push(err.into())).ok())
+        .collect()
+}
+
+/// Checks that `lines` form a rectangle of cells that `valid` accepts, reporting the first bad
+/// cell and any width mismatch on each line.
+pub(crate) fn grid<'a>(
+    src: &Source<'a>,
+    lines: impl IntoIterator<Item = &'a str>,
+    expected: &str,
+    valid: impl Fn(char) -> bool,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut width = None;
+    for line in lines {
+        if let Some(i) = line.find(|c| !valid(c)) {
+            findings.push(src.error(&line[i..], expected).into());
+        }
+        let len = line.chars().count();
+        let width = *width.get_or_insert(len);
+        if len != width {
+            findings.push(Finding::at(
+                src,
+                line,
+                format!("row is {len} wide, expected {width}"),
+            ));
+        }
+    }
+    if width.is_none() {
+        findings.push(Finding::at(src, src.end(), "grid is empty"));
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_reports_every_row() {
+        let src = Source::new(14, "O.#\nOx.\n.#\n...");
+        let findings = grid(&src, src.lines(), "'O', '#' or '.'", |c| {
+            matches!(c, 'O' | '#' | '.')
+        });
+        assert_eq!(
+            vec![
+                "day 14, line 2: column 2: expected 'O', '#' or '.', found \"x.\"",
+                "day 14, line 3: row is 2 wide, expected 3",
+            ],
+            findings.iter().map(|f| f.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unknown_day() {
+        assert_eq!(None, validate(2023, 26, ""));
+        assert_eq!(None, validate(2022, 1, ""));
+    }
+}
@@ -0,0 +1,92 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{solution::Day, validate::Finding};
+
+pub const YEAR: u16 = 2023;
+
+/// Every solved day of 2023, in order.
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+    Day::of::<day21::Day21>(),
+    Day::of::<day22::Day22>(),
+    Day::of::<day23::Day23>(),
+    Day::of::<day24::Day24>(),
+    Day::of::<day25::Day25>(),
+];
+
+/// Checks `input` against everything the solvers for `day` rely on, or `None` if there's no
+/// such day.
+pub(crate) fn validate(day: u8, input: &str) -> Option<Vec<Finding>> {
+    let check: fn(&str) -> Vec<Finding> = match day {
+        1 => day1::validate,
+        2 => day2::validate,
+        3 => day3::validate,
+        4 => day4::validate,
+        5 => day5::validate,
+        6 => day6::validate,
+        7 => day7::validate,
+        8 => day8::validate,
+        9 => day9::validate,
+        10 => day10::validate,
+        11 => day11::validate,
+        12 => day12::validate,
+        13 => day13::validate,
+        14 => day14::validate,
+        15 => day15::validate,
+        16 => day16::validate,
+        17 => day17::validate,
+        18 => day18::validate,
+        19 => day19::validate,
+        20 => day20::validate,
+        21 => day21::validate,
+        22 => day22::validate,
+        23 => day23::validate,
+        24 => day24::validate,
+        25 => day25::validate,
+        _ => return None,
+    };
+    Some(check(input))
+}
@@ -0,0 +1,140 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::Finding,
+};
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A line's calibration value read both ways.
+pub struct Calibration {
+    /// `None` for lines with only spelled-out digits, like those in part 2's example
+    digits: Option<u32>,
+    spelled: u32,
+}
+
+#[aoc_generator(day1)]
+fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    let src = Source::new(1, input);
+    src.lines()
+        .map(|line| {
+            let spelled = spelled_value(line)
+                .ok_or_else(|| src.error(line, "a digit or spelled-out digit"))?;
+            Ok(Calibration {
+                digits: digit_value(line),
+                spelled,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(1, input);
+    let mut findings = Vec::new();
+    for line in src.lines() {
+        if !line.contains(|c: char| c.is_ascii_digit()) {
+            if DIGIT_WORDS.iter().any(|word| line.contains(word)) {
+                findings.push(Finding::at(&src, line, "no digit for part 1"));
+            } else {
+                findings.push(Finding::at(&src, line, "no digit or spelled-out digit"));
+            }
+        }
+    }
+    findings
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day1, part1)]
+fn part1(input: &[Calibration]) -> u32 {
+    // validate warns about lines without a digit, which add nothing here
+    input.iter().filter_map(|c| c.digits).sum()
+}
+
+#[aoc(day1, part2)]
+fn part2(input: &[Calibration]) -> u32 {
+    input.iter().map(|c| c.spelled).sum()
+}
+
+/// The first and last digit in `line` as a two-digit number.
+fn digit_value(line: &str) -> Option<u32> {
+    let mut iter = line.chars().filter_map(|c| c.to_digit(10));
+    let high = iter.next()?;
+    let low = iter.next_back().unwrap_or(high);
+    let value = (high * 10) + low;
+    trace!(line, value, "calibration value");
+    Some(value)
+}
+
+/// The first and last digit in `line`, numeric or spelled out, as a two-digit number.
+fn spelled_value(line: &str) -> Option<u32> {
+    let patterns = [
+        (["1", "one"], 1),
+        (["2", "two"], 2),
+        (["3", "three"], 3),
+        (["4", "four"], 4),
+        (["5", "five"], 5),
+        (["6", "six"], 6),
+        (["7", "seven"], 7),
+        (["8", "eight"], 8),
+        (["9", "nine"], 9),
+    ];
+
+    let matches = patterns
+        .iter()
+        .flat_map(|(ps, val)| {
+            ps.iter().flat_map(|pat| {
+                let first = line.find(pat).map(|pos| (pos, *val));
+                let last = line.rfind(pat).map(|pos| (pos, *val));
+                [first, last].into_iter().flatten()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (_, high) = matches.iter().min_by_key(|(pos, _val)| pos)?;
+    let (_, low) = matches.iter().max_by_key(|(pos, _val)| pos)?;
+    let value = (high * 10) + low;
+    trace!(line, value, "spelled calibration value");
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_lines_without_digits() {
+        let err = parse("1abc2\nnothing").err().unwrap();
+        assert_eq!(
+            "day 1, line 2, column 1: expected a digit or spelled-out digit, found \"nothing\"",
+            err.to_string()
+        );
+        let input = parse("1abc2\ntwone").unwrap();
+        assert_eq!((12, 33), (part1(&input), part2(&input)));
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeSet;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use indexmap::IndexMap;
+
+use crate::{
+    answer::Answer,
+    geometry::{Direction, Point},
+    grid::Grid,
+    parse::{ParseError, Source},
+    search,
+    solution::Solution,
+    trace::{debug, trace},
+    validate::{self, Finding},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pipe {
+    Start,
+    Vert,
+    Horiz,
+    NE,
+    NW,
+    SW,
+    SE,
+}
+
+impl Pipe {
+    fn openings(&self) -> &'static [Direction] {
+        match self {
+            Pipe::Start => &Direction::ALL,
+            Pipe::Vert => &[Direction::Up, Direction::Down],
+            Pipe::Horiz => &[Direction::Right, Direction::Left],
+            Pipe::NE => &[Direction::Up, Direction::Right],
+            Pipe::NW => &[Direction::Up, Direction::Left],
+            Pipe::SE => &[Direction::Down, Direction::Right],
+            Pipe::SW => &[Direction::Down, Direction::Left],
+        }
+    }
+}
+
+pub struct Input {
+    grid: Grid<Option<Pipe>>,
+    /// every tile in the loop with its distance from the start
+    path: IndexMap<Point, usize>,
+}
+
+#[aoc_generator(day10)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(10, input);
+    let grid = Grid::try_parse_with(&src, "a pipe", |c| match c {
+        'S' => Some(Some(Pipe::Start)),
+        '|' => Some(Some(Pipe::Vert)),
+        '-' => Some(Some(Pipe::Horiz)),
+        'L' => Some(Some(Pipe::NE)),
+        'J' => Some(Some(Pipe::NW)),
+        '7' => Some(Some(Pipe::SW)),
+        'F' => Some(Some(Pipe::SE)),
+        '.' => Some(None),
+        _ => None,
+    })?;
+
+    let Some((start, _)) = grid.iter().find(|(_, pipe)| **pipe == Some(Pipe::Start)) else {
+        return Err(src.error(src.end(), "a starting tile 'S'"));
+    };
+
+    // walk outwards from the start in both directions, only following pipes that connect back
+    let path = search::bfs_reachable(start, |&current| {
+        let pipe = grid[current].expect("no pipe");
+        pipe.openings()
+            .iter()
+            .filter_map(|&dir| {
+                let next = grid.step(current, dir)?;
+                let pipe = grid[next]?;
+                pipe.openings().contains(&dir.reverse()).then_some(next)
+            })
+            .collect::<Vec<_>>()
+    });
+    if path.len() < 2 {
+        let line = src
+            .lines()
+            .nth(start.y as usize)
+            .expect("start is in the grid");
+        return Err(src.error(
+            &line[start.x as usize..],
+            "a pipe connecting to the starting tile 'S'",
+        ));
+    }
+
+    Ok(Input { grid, path })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(10, input);
+    let mut findings = validate::grid(&src, src.lines(), "a pipe", |c| "S|-LJ7F.".contains(c));
+    let starts = src
+        .lines()
+        .flat_map(|line| line.match_indices('S').map(|(i, _)| &line[i..]))
+        .collect::<Vec<_>>();
+    if starts.is_empty() {
+        findings.push(Finding::at(&src, src.end(), "no starting tile 'S'"));
+    }
+    for at in starts.iter().skip(1) {
+        findings.push(Finding::at(&src, at, "more than one starting tile 'S'"));
+    }
+    if !findings.is_empty() {
+        return findings;
+    }
+    let Input { grid, path } = match parse(input) {
+        Ok(input) => input,
+        Err(err) => return vec![err.into()],
+    };
+
+    // every pipe in the loop has to connect to a neighbour that connects back
+    let lines = src.lines().collect::<Vec<_>>();
+    for &pos in path.keys() {
+        let pipe = grid[pos].expect("path is all pipes");
+        let connected = pipe
+            .openings()
+            .iter()
+            .filter(|&&dir| {
+                grid.step(pos, dir)
+                    .and_then(|next| grid[next])
+                    .is_some_and(|next| next.openings().contains(&dir.reverse()))
+            })
+            .count();
+        let expected = if pipe == Pipe::Start {
+            2
+        } else {
+            pipe.openings().len()
+        };
+        if connected != expected {
+            findings.push(Finding::at(
+                &src,
+                lines[pos.y as usize],
+                format!("pipe at {pos} has {connected} connections, the loop needs {expected}"),
+            ));
+        }
+    }
+    findings
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day10, part1)]
+fn part1(input: &Input) -> usize {
+    input.path.values().copied().max().expect("path")
+}
+
+#[aoc(day10, part2)]
+fn part2(input: &Input) -> usize {
+    let Input { path, grid } = input;
+
+    let all = grid.positions().collect::<BTreeSet<_>>();
+    let path = path.keys().copied().collect::<BTreeSet<_>>();
+    let mut unknown = &all - &path;
+
+    let mut maybe_interior = BTreeSet::new();
+
+    while let Some(node) = unknown.pop_first() {
+        let mut found_edge = false;
+        let mut seen = path.clone();
+        let mut to_try = vec![node];
+
+        while let Some(n) = to_try.pop() {
+            seen.insert(n);
+
+            for dir in Direction::ALL {
+                if let Some(n) = grid.step(n, dir) {
+                    if !seen.contains(&n) {
+                        to_try.push(n);
+                    }
+                } else {
+                    found_edge = true;
+                }
+            }
+        }
+
+        unknown = &unknown - &seen;
+        if !found_edge {
+            maybe_interior.insert(&seen - &path);
+        }
+    }
+    debug!(
+        regions = maybe_interior.len(),
+        "regions of tiles that can't reach the edge"
+    );
+
+    let expanded = grid
+        .rows()
+        .flat_map(|row| {
+            let mut a = Vec::new();
+            let mut b = Vec::new();
+            let mut c = Vec::new();
+            for x in row {
+                match x {
+                    None => {
+                        a.extend([None, None, None]);
+                        b.extend([None, None, None]);
+                        c.extend([None, None, None]);
+                    }
+                    Some(Pipe::Start) => {
+                        a.extend([None, Some(()), None]);
+                        b.extend([Some(()), Some(()), Some(())]);
+                        c.extend([None, Some(()), None]);
+                    }
+                    Some(Pipe::Horiz) => {
+                        a.extend([None, None, None]);
+                        b.extend([Some(()), Some(()), Some(())]);
+                        c.extend([None, None, None]);
+                    }
+                    Some(Pipe::Vert) => {
+                        a.extend([None, Some(()), None]);
+                        b.extend([None, Some(()), None]);
+                        c.extend([None, Some(()), None]);
+                    }
+                    Some(Pipe::NE) => {
+                        a.extend([None, Some(()), None]);
+                        b.extend([None, Some(()), Some(())]);
+                        c.extend([None, None, None]);
+                    }
+                    Some(Pipe::NW) => {
+                        a.extend([None, Some(()), None]);
+                        b.extend([Some(()), Some(()), None]);
+                        c.extend([None, None, None]);
+                    }
+                    Some(Pipe::SE) => {
+                        a.extend([None, None, None]);
+                        b.extend([None, Some(()), Some(())]);
+                        c.extend([None, Some(()), None]);
+                    }
+                    Some(Pipe::SW) => {
+                        a.extend([None, None, None]);
+                        b.extend([Some(()), Some(()), None]);
+                        c.extend([None, Some(()), None]);
+                    }
+                }
+            }
+            [a, b, c]
+        })
+        .collect::<Vec<_>>();
+    let expanded = Grid::from_rows(expanded);
+
+    let mut interior = 0;
+    for mut region in maybe_interior {
+        let count = region.len();
+        let mut found_edge = false;
+        let mut seen = BTreeSet::new();
+        let start = region.pop_first().expect("region first") * 3;
+        let mut to_try = vec![start];
+
+        while let Some(n) = to_try.pop() {
+            seen.insert(n);
+
+            for dir in Direction::ALL {
+                if let Some(n) = expanded.step(n, dir) {
+                    if !seen.contains(&n) && expanded[n].is_none() {
+                        to_try.push(n);
+                    }
+                } else {
+                    found_edge = true;
+                    break;
+                }
+            }
+        }
+
+        trace!(count, enclosed = !found_edge, "region at {start}");
+        if !found_edge {
+            interior += count;
+        }
+    }
+
+    interior
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_reports_broken_loops() {
+        assert!(validate(".....\n.S-7.\n.|.|.\n.L-J.\n.....").is_empty());
+
+        let findings = validate(".....\n.S-7.\n.|.|.\n.L--.\n...S.");
+        let lines = findings.iter().map(|f| f.line).collect::<Vec<_>>();
+        assert_eq!(vec![5], lines);
+
+        let findings = validate(".....\n.S-7.\n.|.|.\n.L-7.\n.....");
+        let lines = findings.iter().map(|f| f.line).collect::<Vec<_>>();
+        assert_eq!(vec![3, 4], lines);
+    }
+
+    #[test]
+    fn rejects_a_start_with_no_pipes() {
+        let err = parse("S.\n..").err().unwrap();
+        assert_eq!(
+            "day 10, line 1, column 1: expected a pipe connecting to the starting tile 'S', found \"S.\"",
+            err.to_string()
+        );
+        assert_eq!(
+            vec![1],
+            validate("S.\n..")
+                .iter()
+                .map(|f| f.line)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,15 +1,36 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
 
-struct Input {
+use crate::{
+    answer::Answer,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::debug,
+    validate::{self, Finding},
+};
+
+pub struct Input {
     empty_rows: Vec<isize>,
     empty_cols: Vec<isize>,
     galaxies: Vec<(isize, isize)>,
 }
 
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(11, input);
+    let mut findings = validate::grid(&src, src.lines(), "'.' or '#'", |c| matches!(c, '.' | '#'));
+    if input.matches('#').count() < 2 {
+        findings.push(Finding::at(&src, src.end(), "fewer than two galaxies"));
+    }
+    findings
+}
+
 #[aoc_generator(day11)]
-fn parse(input: &str) -> Input {
-    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::try_parse_with(&Source::new(11, input), "'.' or '#'", |c| {
+        matches!(c, '.' | '#').then_some(c)
+    })?;
+    let rows = grid.rows().collect::<Vec<_>>();
 
     let empty_rows = rows
         .iter()
@@ -31,14 +52,20 @@ fn parse(input: &str) -> Input {
         })
         .collect();
 
-    Input {
+    Ok(Input {
         empty_rows,
         empty_cols,
         galaxies,
-    }
+    })
 }
 
 fn translate(input: &Input, factor: isize) -> Vec<(isize, isize)> {
+    debug!(
+        rows = ?input.empty_rows,
+        cols = ?input.empty_cols,
+        factor,
+        "expanding"
+    );
     input
         .galaxies
         .iter()
@@ -61,6 +88,27 @@ fn translate(input: &Input, factor: isize) -> Vec<(isize, isize)> {
         .collect()
 }
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
 #[aoc(day11, part1)]
 fn part1(input: &Input) -> isize {
     let galaxies = translate(input, 1);
@@ -82,32 +130,3 @@ fn part2(input: &Input) -> isize {
         .map(|((x, y), (xx, yy))| (xx - x).abs() + (yy - y).abs())
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example() {
-        assert_eq!(
-            part1(&parse(
-                "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#....."
-            )),
-            374,
-        );
-    }
-
-    #[test]
-    fn part2_example() {
-        assert_eq!(part2(&parse("<EXAMPLE>")), 42);
-    }
-}
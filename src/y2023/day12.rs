@@ -2,7 +2,15 @@ use std::collections::HashMap;
 
 use aoc_runner_derive::{aoc, aoc_generator};
 
-struct Row {
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+pub struct Row {
     conditions: Vec<char>,
     groups: Vec<usize>,
 }
@@ -24,19 +32,29 @@ impl Row {
 }
 
 #[aoc_generator(day12)]
-fn parse(input: &str) -> Vec<Row> {
-    input
-        .lines()
-        .map(|line| {
-            let (conds, groups) = line.split_once(' ').expect("one");
-            let conditions = conds.chars().collect();
-            let groups = groups
-                .split(',')
-                .map(|s| s.parse::<usize>().expect("two"))
-                .collect();
-            Row { conditions, groups }
-        })
-        .collect()
+fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    let src = Source::new(12, input);
+    src.lines().map(|line| parse_row(&src, line)).collect()
+}
+
+fn parse_row<'a>(src: &Source<'a>, line: &'a str) -> Result<Row, ParseError> {
+    let (conds, groups) = src.split_once(line, " ")?;
+    if let Some(i) = conds.find(|c| !matches!(c, '.' | '#' | '?')) {
+        return Err(src.error(&conds[i..], "'.', '#' or '?'"));
+    }
+    let conditions = conds.chars().collect();
+    let groups = groups
+        .split(',')
+        .map(|s| src.parse::<usize>(s, "a group size"))
+        .collect::<Result<_, _>>()?;
+    Ok(Row { conditions, groups })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(12, input);
+    let mut findings = Vec::new();
+    validate::parse_each(src.lines(), |line| parse_row(&src, line), &mut findings);
+    findings
 }
 
 fn group(input: &[char]) -> Vec<(char, usize)> {
@@ -56,12 +74,33 @@ fn group(input: &[char]) -> Vec<(char, usize)> {
     gs
 }
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
 #[aoc(day12, part1)]
 fn part1(input: &[Row]) -> usize {
     let mut combos = 0;
     for row in input {
         let mut possible = vec![row.conditions.clone()];
-        while possible.iter().any(|v| v.iter().any(|c| *c == '?')) {
+        while possible.iter().any(|v| v.contains(&'?')) {
             possible = possible
                 .into_iter()
                 .flat_map(|mut v| {
@@ -95,7 +134,9 @@ fn part2(input: &[Row]) -> num::BigUint {
     let mut combos = num::BigUint::from(0u8);
     for row in input {
         let row = row.unfold();
-        combos += arrangements(&row);
+        let count = arrangements(&row);
+        trace!(%count, "arrangements");
+        combos += count;
     }
     combos
 }
@@ -110,6 +151,7 @@ type Cache = HashMap<(Vec<char>, Vec<usize>, usize), num::BigUint>;
 fn aux(tokens: Vec<char>, counts: Vec<usize>, count: usize, cache: &mut Cache) -> num::BigUint {
     let entry = cache.get(&(tokens.clone(), counts.clone(), count)).cloned();
     if let Some(v) = entry {
+        trace!(?tokens, ?counts, count, "cache hit");
         v.to_owned()
     } else {
         let result = match (&tokens[..], &counts[..], count) {
@@ -135,38 +177,3 @@ fn aux(tokens: Vec<char>, counts: Vec<usize>, count: usize, cache: &mut Cache) -
         result
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example() {
-        assert_eq!(
-            part1(&parse(
-                "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1"
-            )),
-            21
-        );
-    }
-
-    #[test]
-    fn part2_example() {
-        assert_eq!(
-            part2(&parse(
-                "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1"
-            )),
-            525152usize.into()
-        );
-    }
-}
@@ -0,0 +1,165 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+#[aoc_generator(day13)]
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let src = Source::new(13, input);
+    input
+        .split("\n\n")
+        .map(|block| {
+            let grid = Grid::try_parse_lines(&src, block.lines(), "'.' or '#'", |c| {
+                matches!(c, '.' | '#').then_some(c)
+            })?;
+            Ok(Pattern { grid })
+        })
+        .collect()
+}
+
+pub struct Pattern {
+    grid: Grid<char>,
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(13, input);
+    let mut findings = Vec::new();
+    for block in input.split("\n\n") {
+        let block_findings = validate::grid(&src, block.lines(), "'.' or '#'", |c| {
+            matches!(c, '.' | '#')
+        });
+        if !block_findings.is_empty() {
+            findings.extend(block_findings);
+            continue;
+        }
+        let Ok(grid) = Grid::try_parse_lines(&src, block.lines(), "'.' or '#'", Some) else {
+            continue;
+        };
+        let pattern = Pattern { grid };
+        let (row, smudged_row) = find_row(&pattern);
+        let (col, smudged_col) = find_col(&pattern);
+        if row.is_none() && col.is_none() {
+            findings.push(Finding::at(
+                &src,
+                block,
+                "pattern has no line of reflection",
+            ));
+        }
+        if smudged_row.is_none() && smudged_col.is_none() {
+            findings.push(Finding::at(
+                &src,
+                block,
+                "pattern has no line of reflection with one smudge",
+            ));
+        }
+    }
+    findings
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day13, part1)]
+fn part1(input: &[Pattern]) -> usize {
+    let mut y_mirrors = Vec::new();
+    let mut x_mirrors = Vec::new();
+    for pattern in input {
+        if let (Some(y), _) = find_row(pattern) {
+            trace!(y, "mirror between rows");
+            y_mirrors.push(y);
+            continue;
+        }
+        if let (Some(x), _) = find_col(pattern) {
+            trace!(x, "mirror between columns");
+            x_mirrors.push(x);
+            continue;
+        }
+        panic!("no mirror found")
+    }
+
+    x_mirrors.iter().sum::<usize>() + (100 * y_mirrors.iter().sum::<usize>())
+}
+
+fn find_row(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
+    find_mirror(&pattern.grid)
+}
+
+fn find_col(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
+    find_mirror(&pattern.grid.transpose())
+}
+
+fn find_mirror(grid: &Grid<char>) -> (Option<usize>, Option<usize>) {
+    let pattern = grid.rows().collect::<Vec<_>>();
+    let mut zero = None;
+    let mut one = None;
+    for y in 0..pattern.len() {
+        let (above, below) = pattern.split_at(y);
+
+        let len = above.len().min(below.len());
+        if len == 0 {
+            continue;
+        }
+        let mut below = below[0..len].to_vec();
+        below.reverse();
+        let dist = dist2(&above[(y - len)..y], &below[0..len]);
+        if dist == 0 {
+            zero = Some(y);
+        } else if dist == 1 {
+            one = Some(y);
+        }
+    }
+    (zero, one)
+}
+
+fn dist(a: &[char], b: &[char]) -> usize {
+    a.iter().zip(b.iter()).filter(|(a, b)| a != b).count()
+}
+
+fn dist2(a: &[&[char]], b: &[&[char]]) -> usize {
+    a.iter().zip(b.iter()).map(|(a, b)| dist(a, b)).sum()
+}
+
+#[aoc(day13, part2)]
+fn part2(input: &[Pattern]) -> usize {
+    let mut y_mirrors = Vec::new();
+    let mut x_mirrors = Vec::new();
+    for pattern in input {
+        if let (_, Some(y)) = find_row(pattern) {
+            trace!(y, "smudged mirror between rows");
+            y_mirrors.push(y);
+            continue;
+        }
+        if let (_, Some(x)) = find_col(pattern) {
+            trace!(x, "smudged mirror between columns");
+            x_mirrors.push(x);
+            continue;
+        }
+        panic!("no mirror found")
+    }
+
+    x_mirrors.iter().sum::<usize>() + (100 * y_mirrors.iter().sum::<usize>())
+}
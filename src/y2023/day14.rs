@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    cycle,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::{Alternative, Solution},
+    trace::{debug, trace},
+    validate::{self, Finding},
+};
+
+#[aoc_generator(day14)]
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse_with(&Source::new(14, input), "'O', '#' or '.'", |c| {
+        matches!(c, 'O' | '#' | '.').then_some(c)
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(14, input);
+    validate::grid(&src, src.lines(), "'O', '#' or '.'", |c| {
+        matches!(c, 'O' | '#' | '.')
+    })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Grid<char>;
+
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[Alternative {
+        part: 1,
+        name: "direct",
+        solve: |input| part1_direct(input).into(),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day14, part1)]
+fn part1(input: &Grid<char>) -> usize {
+    let mut flipped = input.transpose();
+
+    let mut cache: HashMap<Vec<char>, Vec<char>> = HashMap::new();
+    tilt(&mut flipped, &mut cache);
+
+    load(&flipped.transpose())
+}
+
+/// Scores each rock where it would come to rest, without moving any of them.
+fn part1_direct(input: &Grid<char>) -> usize {
+    let height = input.height();
+    input
+        .transpose()
+        .rows()
+        .map(|column| {
+            let mut resting = 0;
+            let mut load = 0;
+            for (i, &c) in column.iter().enumerate() {
+                match c {
+                    '#' => resting = i + 1,
+                    'O' => {
+                        load += height - resting;
+                        resting += 1;
+                    }
+                    _ => {}
+                }
+            }
+            load
+        })
+        .sum()
+}
+
+#[aoc(day14, part2)]
+fn part2(input: &Grid<char>) -> usize {
+    let mut row_cache: HashMap<Vec<char>, Vec<char>> = HashMap::new();
+    let spin = |grid: &Grid<char>| {
+        let mut result = grid.clone();
+        for _ in 0..4 {
+            tilt(&mut result, &mut row_cache);
+            result = result.rotate_ccw();
+        }
+        result
+    };
+
+    let grid = cycle::fast_forward(input.transpose(), spin, 1_000_000_000);
+    debug!(rows = row_cache.len(), "distinct rows tilted");
+    load(&grid.transpose())
+}
+
+fn load(grid: &Grid<char>) -> usize {
+    let scores = (1..=grid.height()).rev();
+    grid.rows()
+        .zip(scores)
+        .map(|(row, score)| row.iter().filter(|&c| *c == 'O').count() * score)
+        .sum()
+}
+
+fn tilt(grid: &mut Grid<char>, cache: &mut HashMap<Vec<char>, Vec<char>>) {
+    for row in grid.rows_mut() {
+        if let Some(res) = cache.get(&*row) {
+            trace!(row = %row.iter().collect::<String>(), "cache hit");
+            row.copy_from_slice(res);
+            continue;
+        }
+        let orig = row.to_vec();
+
+        let positions = row.iter().positions(|&c| c == 'O').collect_vec();
+        for from in positions {
+            if from == 0 {
+                continue;
+            }
+
+            if let Some(p) = row[0..from].iter().rposition(|&c| c == '#' || c == 'O') {
+                let to = p + 1;
+                trace!(from, to, "rock rolls");
+                if from != to {
+                    assert_eq!('.', row[to]);
+                    row[to] = 'O';
+                    row[from] = '.';
+                }
+            } else {
+                trace!(from, to = 0, "rock rolls");
+                row[0] = 'O';
+                row[from] = '.';
+            }
+        }
+        cache.insert(orig, row.to_vec());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_alternatives_agree() {
+        let grid =
+            parse(include_str!("../../input/2023/examples/day14/example.txt").trim_end()).unwrap();
+        assert_eq!(136, part1_direct(&grid));
+        assert_eq!(part1(&grid), part1_direct(&grid));
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use indexmap::IndexMap;
+use winnow::{
+    ascii::{alpha1, digit1},
+    combinator::alt,
+    PResult, Parser,
+};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+#[aoc_generator(day15)]
+fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    let src = Source::new(15, input);
+    steps(input).map(|text| parse_step(&src, text)).collect()
+}
+
+fn steps(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_matches('\n').split(',')
+}
+
+fn parse_step<'a>(src: &Source<'a>, text: &'a str) -> Result<Step, ParseError> {
+    let instr = parse_instr
+        .parse(text)
+        .map_err(|e| src.error(&text[e.offset()..], "a label then '-' or '=N'"))?;
+    Ok(Step {
+        text: text.to_string(),
+        instr,
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(15, input);
+    let mut findings = Vec::new();
+    validate::parse_each(steps(input), |text| parse_step(&src, text), &mut findings);
+    findings
+}
+
+pub struct Step {
+    text: String,
+    instr: Instr,
+}
+
+fn hash(s: &str) -> u32 {
+    s.chars().fold(0, |acc, c| ((acc + c as u32) * 17) % 256)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day15, part1)]
+fn part1(input: &[Step]) -> u32 {
+    input.iter().map(|step| hash(&step.text)).sum()
+}
+
+#[aoc(day15, part2)]
+fn part2(input: &[Step]) -> usize {
+    let mut boxes: [IndexMap<&str, usize>; 256] = std::array::from_fn(|_| IndexMap::new());
+
+    for step in input {
+        let instr = &step.instr;
+
+        let idx = hash(&instr.label) as usize;
+        let boxx = &mut boxes[idx];
+
+        match instr.op {
+            Op::Remove => {
+                boxx.shift_remove(instr.label.as_str());
+            }
+            Op::Add(len) => {
+                boxx.insert(&instr.label, len);
+            }
+        }
+        trace!(step = step.text, box_index = idx, lenses = ?boxes[idx]);
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, b)| {
+            b.values().enumerate().map(move |(j, f)| {
+                trace!(box_index = i, slot = j, focal_length = f);
+                (i + 1) * (j + 1) * f
+            })
+        })
+        .sum()
+}
+
+fn parse_instr(s: &mut &str) -> PResult<Instr> {
+    Ok(Instr {
+        label: alpha1.parse_next(s)?.to_string(),
+        op: parse_op(s)?,
+    })
+}
+
+fn parse_op(s: &mut &str) -> PResult<Op> {
+    alt((
+        ('-'.map(|_| Op::Remove)),
+        ('=', digit1.parse_to()).map(|(_, f)| Op::Add(f)),
+    ))
+    .parse_next(s)
+}
+
+struct Instr {
+    label: String,
+    op: Op,
+}
+
+enum Op {
+    Remove,
+    Add(usize),
+}
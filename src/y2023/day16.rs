@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use indexmap::IndexSet;
+
+use crate::{
+    answer::Answer,
+    geometry::{Direction, Point},
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+#[aoc_generator(day16)]
+fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::try_parse(&Source::new(16, input), "a tile")
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(16, input);
+    validate::grid(&src, src.lines(), "a tile", |c| Tile::try_from(c).is_ok())
+}
+
+#[derive(Debug)]
+pub enum Tile {
+    Empty,
+    MirrorUp,
+    MirrorDown,
+    SplitterVert,
+    SplitterHoriz,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorUp),
+            '\\' => Ok(Tile::MirrorDown),
+            '|' => Ok(Tile::SplitterVert),
+            '-' => Ok(Tile::SplitterHoriz),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    pos: Point,
+    dir: Direction,
+}
+
+impl Beam {
+    fn next(&self, grid: &Grid<Tile>) -> Vec<Self> {
+        trace!(beam = ?self);
+        let dirs = match &grid[self.pos] {
+            Tile::Empty => {
+                vec![self.dir]
+            }
+            Tile::MirrorUp => {
+                vec![match self.dir {
+                    Direction::Right => Direction::Up,
+                    Direction::Left => Direction::Down,
+                    Direction::Up => Direction::Right,
+                    Direction::Down => Direction::Left,
+                }]
+            }
+            Tile::MirrorDown => {
+                vec![match self.dir {
+                    Direction::Right => Direction::Down,
+                    Direction::Left => Direction::Up,
+                    Direction::Up => Direction::Left,
+                    Direction::Down => Direction::Right,
+                }]
+            }
+            Tile::SplitterVert => match self.dir {
+                Direction::Right | Direction::Left => vec![Direction::Up, Direction::Down],
+                Direction::Up | Direction::Down => vec![self.dir],
+            },
+            Tile::SplitterHoriz => match self.dir {
+                Direction::Right | Direction::Left => vec![self.dir],
+                Direction::Up | Direction::Down => vec![Direction::Left, Direction::Right],
+            },
+        };
+
+        dirs.into_iter()
+            .filter_map(|dir| {
+                let pos = grid.step(self.pos, dir)?;
+                Some(Beam { pos, dir })
+            })
+            .collect()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day16, part1)]
+fn part1(grid: &Grid<Tile>) -> usize {
+    solve(grid, Point::ORIGIN, Direction::Right)
+}
+
+#[cfg_attr(
+    feature = "trace",
+    tracing::instrument(level = "debug", skip(grid), ret)
+)]
+fn solve(grid: &Grid<Tile>, pos: Point, dir: Direction) -> usize {
+    let mut beams = IndexSet::new();
+    beams.insert(Beam { pos, dir });
+    let mut energized = HashSet::new();
+    let mut prev_states = HashSet::new();
+    while let Some(beam) = beams.pop() {
+        energized.insert(beam.pos);
+        if prev_states.insert(beam) {
+            beams.extend(beam.next(grid))
+        }
+    }
+    trace!(?energized);
+    energized.len()
+}
+
+#[aoc(day16, part2)]
+fn part2(grid: &Grid<Tile>) -> usize {
+    let (width, height) = (grid.width() as i64, grid.height() as i64);
+    let left = (0..height).map(|y| solve(grid, Point::new(0, y), Direction::Right));
+    let right = (0..height).map(|y| solve(grid, Point::new(width - 1, y), Direction::Left));
+    let top = (0..width).map(|x| solve(grid, Point::new(x, 0), Direction::Down));
+    let bottom = (0..width).map(|x| solve(grid, Point::new(x, height - 1), Direction::Up));
+
+    left.chain(right).chain(top).chain(bottom).max().unwrap()
+}
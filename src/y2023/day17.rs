@@ -0,0 +1,116 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    geometry::{Direction, Point},
+    grid::Grid,
+    parse::{ParseError, Source},
+    search,
+    solution::Solution,
+    validate::{self, Finding},
+};
+
+#[aoc_generator(day17)]
+fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::try_parse_with(&Source::new(17, input), "a digit", |c| {
+        c.to_digit(10).map(|d| d as usize)
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(17, input);
+    validate::grid(&src, src.lines(), "a digit", |c| c.is_ascii_digit())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    pos: Point,
+    dir: Direction,
+    dir_len: u8,
+}
+
+type DirFn = fn(&State) -> Vec<Direction>;
+
+impl State {
+    fn next(&self, grid: &Grid<usize>, dir_fn: DirFn) -> Vec<(Self, usize)> {
+        let dirs = dir_fn(self);
+
+        dirs.into_iter()
+            .filter_map(|dir| {
+                let dir_len = if dir == self.dir { self.dir_len + 1 } else { 1 };
+                let pos = grid.step(self.pos, dir)?;
+                Some((State { pos, dir, dir_len }, grid[pos]))
+            })
+            .collect()
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day17, part1)]
+fn part1(grid: &Grid<usize>) -> usize {
+    solve(grid, normal)
+}
+
+#[aoc(day17, part2)]
+fn part2(grid: &Grid<usize>) -> usize {
+    solve(grid, ultra)
+}
+
+#[cfg_attr(feature = "trace", tracing::instrument(level = "debug", skip_all, ret))]
+fn solve(grid: &Grid<usize>, dir_fn: DirFn) -> usize {
+    let goal = Point::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+    let start = State {
+        pos: Point::ORIGIN,
+        dir: Direction::Right, // doesn't matter
+        dir_len: 0,
+    };
+
+    let (cost, _path) = search::astar(
+        start,
+        |state| state.next(grid, dir_fn),
+        |state| state.pos == goal,
+        |state| state.pos.manhattan(goal) as usize,
+    )
+    .expect("didn't find one");
+    cost
+}
+
+fn normal(state: &State) -> Vec<Direction> {
+    let turns = [state.dir.turn_left(), state.dir.turn_right()];
+    if state.dir_len < 3 {
+        vec![state.dir, turns[0], turns[1]]
+    } else {
+        turns.to_vec()
+    }
+}
+
+fn ultra(state: &State) -> Vec<Direction> {
+    let turns = [state.dir.turn_left(), state.dir.turn_right()];
+    if state.dir_len < 4 {
+        vec![state.dir]
+    } else if state.dir_len < 10 {
+        vec![state.dir, turns[0], turns[1]]
+    } else {
+        turns.to_vec()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    answer::Answer,
+    geometry::{Direction, Point},
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::{debug, trace},
+    validate::{self, Finding},
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+#[derive(Debug, Clone)]
+struct Instr {
+    dir: Direction,
+    len: usize,
+}
+
+/// The dig plan as written, and as decoded from the hex colour codes.
+pub struct Plan {
+    instrs: Vec<Instr>,
+    decoded: Vec<Instr>,
+}
+
+#[aoc_generator(day18)]
+fn parse(input: &str) -> Result<Plan, ParseError> {
+    let src = Source::new(18, input);
+    let (instrs, decoded) = src
+        .lines()
+        .map(|line| parse_line(&src, line))
+        .collect::<Result<_, _>>()?;
+    Ok(Plan { instrs, decoded })
+}
+
+/// A line's instruction as written and as decoded from its colour.
+fn parse_line<'a>(src: &Source<'a>, line: &'a str) -> Result<(Instr, Instr), ParseError> {
+    let mut parts = line.split_whitespace();
+    let d = src.next(&mut parts, "a direction")?;
+    let dir = match d {
+        "R" => Direction::Right,
+        "L" => Direction::Left,
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        _ => return Err(src.error(d, "'R', 'L', 'U' or 'D'")),
+    };
+    let len = src.parse(src.next(&mut parts, "a length")?, "a length")?;
+    let instr = Instr { dir, len };
+
+    let color = src.next(&mut parts, "a colour")?;
+    let hex = src.strip_prefix(color, "(#")?;
+    let hex = hex
+        .strip_suffix(')')
+        .filter(|hex| hex.len() == 6)
+        .ok_or_else(|| src.error(hex, "six hex digits then ')'"))?;
+    let len = usize::from_str_radix(&hex[..5], 16).map_err(|_| src.error(hex, "a hex length"))?;
+    let dir = match &hex[5..] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => return Err(src.error(&hex[5..], "a direction digit 0-3")),
+    };
+    Ok((instr, Instr { dir, len }))
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(18, input);
+    let mut findings = Vec::new();
+    let (instrs, decoded): (Vec<_>, Vec<_>) =
+        validate::parse_each(src.lines(), |line| parse_line(&src, line), &mut findings)
+            .into_iter()
+            .unzip();
+    if !findings.is_empty() {
+        return findings;
+    }
+    for (name, plan) in [("dig plan", instrs), ("decoded dig plan", decoded)] {
+        let end = plan.iter().fold(Point::ORIGIN, |p, instr| {
+            p + instr.dir.delta() * instr.len as i64
+        });
+        if end != Point::ORIGIN {
+            findings.push(Finding::at(
+                &src,
+                src.end(),
+                format!("{name} ends at {end} instead of back at the start"),
+            ));
+        }
+    }
+    findings
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = Plan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day18, part1)]
+fn part1(input: &Plan) -> usize {
+    solve(&input.instrs)
+}
+
+fn solve(input: &[Instr]) -> usize {
+    // shoelace formula for the area enclosed by the path's centreline, then pick's theorem to
+    // count the lattice points inside it plus the trench itself
+    let mut current = Point::ORIGIN;
+    let mut area = 0;
+    let mut path_len = 0;
+    for instr in input {
+        let next = current + instr.dir.delta() * instr.len as i64;
+        area += current.x * next.y - next.x * current.y;
+        path_len += instr.len as i64;
+        current = next;
+        trace!(%current, area, path_len);
+    }
+    assert_eq!(Point::ORIGIN, current, "path should be closed");
+
+    let interior_count = (area.abs() - path_len) / 2 + 1;
+    debug!(interior_count, path_len, "lagoon");
+    (interior_count + path_len) as usize
+}
+
+#[aoc(day18, part2)]
+fn part2(input: &Plan) -> usize {
+    solve(&input.decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_bad_lines() {
+        let err = parse("R 6 (#70c710)\nX 5 (#0dc571)").err().unwrap();
+        assert_eq!((18, 2, 1), (err.day, err.line, err.column));
+
+        let err = parse("R 6 (#70c710)\nD 5 (#0dc57").err().unwrap();
+        assert_eq!(
+            "day 18, line 2, column 7: expected six hex digits then ')', found \"0dc57\"",
+            err.to_string()
+        );
+    }
+}
@@ -1,15 +1,25 @@
 use core::fmt;
-use std::{collections::HashMap, ops::Range};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
 
 use aoc_runner_derive::{aoc, aoc_generator};
 use winnow::{
     ascii::{alpha1, digit1},
-    combinator::{delimited, opt, separated},
-    token::one_of,
+    combinator::{alt, delimited, opt, separated},
     PResult, Parser,
 };
 
-struct Input {
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+pub struct Input {
     parts: Vec<Part>,
     workflows: HashMap<String, Workflow>,
 }
@@ -149,25 +159,6 @@ enum Field {
     S,
 }
 
-impl Cond {
-    fn new((field, op, value, _): (&str, char, &str, char)) -> Self {
-        let field = match field {
-            "x" => Field::X,
-            "m" => Field::M,
-            "a" => Field::A,
-            "s" => Field::S,
-            _ => panic!("bad field"),
-        };
-        let value: usize = value.parse().expect("val");
-        let op = match op {
-            '<' => Op::Lt(value),
-            '>' => Op::Gt(value),
-            _ => panic!("bad op"),
-        };
-        Self { field, op }
-    }
-}
-
 impl fmt::Display for Cond {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("{:?} {}", self.field, self.op))
@@ -199,15 +190,26 @@ fn parse_workflow(input: &mut &str) -> PResult<Workflow> {
 }
 
 fn parse_rule(input: &mut &str) -> PResult<Rule> {
-    let (cond, dest) = (
-        opt((alpha1, one_of(['<', '>']), digit1, ':').map(Cond::new)),
-        alpha1.map(String::from),
-    )
-        .parse_next(input)?;
+    let (cond, dest) = (opt(parse_cond), alpha1.map(String::from)).parse_next(input)?;
 
     Ok(Rule { cond, dest })
 }
 
+fn parse_cond(input: &mut &str) -> PResult<Cond> {
+    let field = alt((
+        'x'.value(Field::X),
+        'm'.value(Field::M),
+        'a'.value(Field::A),
+        's'.value(Field::S),
+    ))
+    .parse_next(input)?;
+    let lt = alt(('<'.value(true), '>'.value(false))).parse_next(input)?;
+    let value = digit1.parse_to().parse_next(input)?;
+    let _ = ':'.parse_next(input)?;
+    let op = if lt { Op::Lt(value) } else { Op::Gt(value) };
+    Ok(Cond { field, op })
+}
+
 fn parse_part(input: &mut &str) -> PResult<Part> {
     let _ = "{x=".parse_next(input)?;
     let x = digit1.parse_to().parse_next(input)?;
@@ -222,20 +224,141 @@ fn parse_part(input: &mut &str) -> PResult<Part> {
 }
 
 #[aoc_generator(day19)]
-fn parse(input: &str) -> Input {
-    let (workflows, parts) = input.split_once("\n\n").expect("once");
-    let workflows = workflows
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(19, input);
+    let (workflow_lines, parts) = src.split_once(input, "\n\n")?;
+    let parsed = workflow_lines
         .lines()
-        .map(|line| parse_workflow.parse(line).expect("workflow"))
-        .map(|workflow| (workflow.name.clone(), workflow))
+        .map(|line| Ok((line, parse_workflow_line(&src, line)?)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(err) = check_links(&src, workflow_lines, &parsed)
+        .into_iter()
+        .next()
+    {
+        return Err(err);
+    }
+    let workflows = parsed
+        .into_iter()
+        .map(|(_, workflow)| (workflow.name.clone(), workflow))
         .collect();
 
     let parts = parts
         .lines()
-        .map(|line| parse_part.parse(line).expect("part"))
-        .collect();
+        .map(|line| parse_part_line(&src, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input { parts, workflows })
+}
+
+fn parse_workflow_line<'a>(src: &Source<'a>, line: &'a str) -> Result<Workflow, ParseError> {
+    parse_workflow
+        .parse(line)
+        .map_err(|e| src.error(&line[e.offset()..], "a workflow"))
+}
 
-    Input { parts, workflows }
+fn parse_part_line<'a>(src: &Source<'a>, line: &'a str) -> Result<Part, ParseError> {
+    parse_part
+        .parse(line)
+        .map_err(|e| src.error(&line[e.offset()..], "a part"))
+}
+
+/// Every way the workflows fail to lead from `in` to 'A' or 'R': a missing `in`, unknown
+/// destinations, and loops that would send a part round forever.
+fn check_links<'a>(
+    src: &Source<'a>,
+    workflow_lines: &'a str,
+    parsed: &[(&'a str, Workflow)],
+) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    let by_name = parsed
+        .iter()
+        .map(|(line, workflow)| (workflow.name.as_str(), (*line, workflow)))
+        .collect::<HashMap<_, _>>();
+    let dest_at = |line: &'a str, dest: &str| line.rfind(dest).map_or(line, |i| &line[i..]);
+
+    if !by_name.contains_key("in") {
+        errors.push(src.error(workflow_lines, "a workflow named \"in\""));
+    }
+    for (line, workflow) in parsed {
+        for rule in &workflow.rules {
+            let dest = rule.dest.as_str();
+            if !matches!(dest, "A" | "R") && !by_name.contains_key(dest) {
+                errors.push(src.error(dest_at(line, dest), "a known workflow, 'A' or 'R'"));
+            }
+        }
+    }
+
+    // depth first from `in`, where reaching a workflow still on the stack means a loop
+    let mut stack = vec![("in", 0)];
+    let mut on_stack = HashSet::from(["in"]);
+    let mut done = HashSet::new();
+    while let Some((name, rule)) = stack.pop() {
+        let Some(&(line, workflow)) = by_name.get(name) else {
+            continue;
+        };
+        let Some(dest) = workflow.rules.get(rule).map(|rule| rule.dest.as_str()) else {
+            on_stack.remove(name);
+            done.insert(name);
+            continue;
+        };
+        stack.push((name, rule + 1));
+        if on_stack.contains(dest) {
+            errors.push(src.error(
+                dest_at(line, dest),
+                format!("a destination that doesn't loop back to {dest:?}"),
+            ));
+        } else if by_name.contains_key(dest) && !done.contains(dest) {
+            on_stack.insert(dest);
+            stack.push((dest, 0));
+        }
+    }
+    errors
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(19, input);
+    let (workflow_lines, parts) = match src.split_once(input, "\n\n") {
+        Ok(sections) => sections,
+        Err(err) => return vec![err.into()],
+    };
+    let mut findings = Vec::new();
+    let parsed = validate::parse_each(
+        workflow_lines.lines(),
+        |line| Ok((line, parse_workflow_line(&src, line)?)),
+        &mut findings,
+    );
+    findings.extend(
+        check_links(&src, workflow_lines, &parsed)
+            .into_iter()
+            .map(Finding::from),
+    );
+    validate::parse_each(
+        parts.lines(),
+        |line| parse_part_line(&src, line),
+        &mut findings,
+    );
+    findings
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
 
 #[aoc(day19, part1)]
@@ -244,6 +367,7 @@ fn part1(input: &Input) -> usize {
     for part in &input.parts {
         let mut key = "in";
         'part: loop {
+            trace!(?part, workflow = key);
             let workflow = &input.workflows[key];
             'rules: for rule in &workflow.rules {
                 if let Some(dest) = rule.apply(part) {
@@ -267,26 +391,25 @@ fn part1(input: &Input) -> usize {
 
 #[aoc(day19, part2)]
 fn part2(input: &Input) -> usize {
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "trace", skip(workflows), ret)
+    )]
     fn rec<'a>(
         workflows: &'a HashMap<String, Workflow>,
         mut range: PartRange,
         key: &'a str,
     ) -> usize {
         if range.is_empty() {
-            // println!("empty range = 0\n");
             return 0;
         }
 
         if key == "A" {
-            // println!("accepted {range:?} = {}\n", range.combos());
             return range.combos();
         } else if key == "R" {
-            // println!("rejected = 0\n");
             return 0;
         }
 
-        // println!("starting range {range:?} key {key}");
-
         let mut total = 0;
         let w = &workflows[key];
         for rule in &w.rules {
@@ -294,11 +417,10 @@ fn part2(input: &Input) -> usize {
                 return 0;
             }
             if let Some(cond) = &rule.cond {
-                // println!("splitting with cond {cond}");
+                trace!(%cond, "splitting");
                 let (pass, fail) = cond.split(range);
                 total += rec(workflows, pass, &rule.dest);
                 range = fail;
-                // println!("continuing with fail range {range:?}");
             } else {
                 total += rec(workflows, range, rule.dest.as_str());
                 break; // this will be the last anyway
@@ -315,54 +437,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part1_example() {
-        assert_eq!(
-            part1(&parse(
-                "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}"
-            )),
-            19114
-        );
-    }
-
-    #[test]
-    fn part2_example() {
+    fn validate_reports_bad_links() {
+        let findings = validate("in{x<10:ab,cd}\nab{m>5:in,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1}");
         assert_eq!(
-            part2(&parse(
-                "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}"
-            )),
-            167409079868000
+            vec![
+                "day 19, line 1: column 12: expected a known workflow, 'A' or 'R', found \"cd}\"",
+                "day 19, line 2: column 8: expected a destination that doesn't loop back to \"in\", found \"in,R}\"",
+                "day 19, line 5: column 5: expected a part, found \"}\"",
+            ],
+            findings.iter().map(|f| f.to_string()).collect::<Vec<_>>()
         );
     }
 }
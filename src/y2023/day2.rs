@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+#[aoc_generator(day2)]
+fn generator(input: &str) -> Result<HashMap<u32, HashMap<String, u32>>, ParseError> {
+    let src = Source::new(2, input);
+    src.lines().map(|line| parse_game(&src, line)).collect()
+}
+
+fn parse_game<'a>(
+    src: &Source<'a>,
+    line: &'a str,
+) -> Result<(u32, HashMap<String, u32>), ParseError> {
+    let (game, rest) = src.split_once(line, ": ")?;
+    let num = src.strip_prefix(game, "Game ")?;
+    let game_num: u32 = src.parse(num, "a game number")?;
+
+    let mut maxes: HashMap<String, u32> = HashMap::new();
+    for game in rest.split(';') {
+        for color in game.split(',') {
+            let (count, color) = src.split_once(color.trim(), " ")?;
+            let count: u32 = src.parse(count, "a cube count")?;
+            if !COLORS.contains(&color) {
+                return Err(src.error(color, "red, green or blue"));
+            }
+            let entry = maxes.entry(color.to_string()).or_default();
+            if count > *entry {
+                *entry = count;
+            }
+        }
+    }
+    Ok((game_num, maxes))
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(2, input);
+    let mut findings = Vec::new();
+    let games = validate::parse_each(
+        src.lines(),
+        |line| parse_game(&src, line).map(|game| (line, game)),
+        &mut findings,
+    );
+    for (line, (_, maxes)) in games {
+        let missing = COLORS
+            .into_iter()
+            .filter(|color| !maxes.contains_key(*color))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            findings.push(Finding::at(
+                &src,
+                line,
+                format!("game never shows {}", missing.join(" or ")),
+            ));
+        }
+    }
+    findings
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = HashMap<u32, HashMap<String, u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day2, part1)]
+fn part1(maxes_per_game: &HashMap<u32, HashMap<String, u32>>) -> u32 {
+    maxes_per_game
+        .iter()
+        .filter_map(|(key, vals)| {
+            let possible = vals["red"] <= 12 && vals["green"] <= 13 && vals["blue"] <= 14;
+            trace!(game = key, ?vals, possible);
+            possible.then_some(key)
+        })
+        .sum()
+}
+
+#[aoc(day2, part2)]
+fn part2(maxes_per_game: &HashMap<u32, HashMap<String, u32>>) -> u32 {
+    maxes_per_game
+        .values()
+        .map(|vals| vals["red"] * vals["green"] * vals["blue"])
+        .sum()
+}
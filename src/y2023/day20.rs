@@ -8,6 +8,14 @@ use winnow::{
     PResult, Parser,
 };
 
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::{debug, trace},
+    validate::{self, Finding},
+};
+
 #[derive(Debug, Clone)]
 enum ModuleType {
     Broadcast,
@@ -16,7 +24,7 @@ enum ModuleType {
 }
 
 #[derive(Debug, Clone)]
-struct Module {
+pub struct Module {
     ty: ModuleType,
     name: String,
     destinations: Vec<String>,
@@ -78,14 +86,74 @@ fn parse_module(input: &mut &str) -> PResult<Module> {
 }
 
 #[aoc_generator(day20)]
-fn parse(input: &str) -> HashMap<String, Module> {
-    input
-        .lines()
-        .map(|line| parse_module.parse(line).expect("module"))
-        .map(|module| (module.name.clone(), module))
+fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let src = Source::new(20, input);
+    src.lines()
+        .map(|line| {
+            let module = parse_module_line(&src, line)?;
+            Ok((module.name.clone(), module))
+        })
         .collect()
 }
 
+fn parse_module_line<'a>(src: &Source<'a>, line: &'a str) -> Result<Module, ParseError> {
+    parse_module
+        .parse(line)
+        .map_err(|e| src.error(&line[e.offset()..], "a module"))
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(20, input);
+    let mut findings = Vec::new();
+    let modules = validate::parse_each(
+        src.lines(),
+        |line| Ok((line, parse_module_line(&src, line)?)),
+        &mut findings,
+    );
+    if !findings.is_empty() {
+        return findings;
+    }
+    let feeding = |dest: &str| {
+        modules
+            .iter()
+            .filter(move |(_, m)| m.destinations.iter().any(|d| d == dest))
+            .collect_vec()
+    };
+
+    if !modules.iter().any(|(_, m)| m.name == "broadcast") {
+        findings.push(Finding::at(&src, src.end(), "no broadcaster"));
+    }
+    // part 2 assumes rx is fed by a single conjunction, zh, whose inputs it watches by name
+    match feeding("rx")[..] {
+        [] => findings.push(Finding::at(&src, src.end(), "nothing sends to rx")),
+        [(line, m)] => {
+            if m.name != "zh" || !matches!(m.ty, ModuleType::Conjunction(_)) {
+                findings.push(Finding::at(
+                    &src,
+                    line,
+                    "rx is not fed by the conjunction zh",
+                ));
+            } else {
+                let inputs = feeding("zh")
+                    .iter()
+                    .map(|(_, m)| m.name.as_str())
+                    .sorted()
+                    .collect_vec();
+                let expected = PART2_INPUTS.into_iter().sorted().collect_vec();
+                if inputs != expected {
+                    findings.push(Finding::at(
+                        &src,
+                        line,
+                        format!("zh is fed by {inputs:?} instead of {expected:?}"),
+                    ));
+                }
+            }
+        }
+        [_, (line, _), ..] => findings.push(Finding::at(&src, line, "rx has more than one input")),
+    }
+    findings
+}
+
 fn initialize(modules: &mut HashMap<String, Module>) {
     let conj_keys = modules
         .values()
@@ -109,6 +177,27 @@ fn initialize(modules: &mut HashMap<String, Module>) {
     }
 }
 
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input = HashMap<String, Module>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
 #[aoc(day20, part1)]
 fn part1(input: &HashMap<String, Module>) -> usize {
     let mut modules = input.clone();
@@ -122,7 +211,7 @@ fn part1(input: &HashMap<String, Module>) -> usize {
         let mut next = Vec::new();
         while !signals.is_empty() {
             for (k, f, v) in signals.drain(..) {
-                // println!("{f} -> {v} -> {k}");
+                trace!(from = f, to = k, high = v, "pulse");
                 if v {
                     high += 1;
                 } else {
@@ -131,7 +220,7 @@ fn part1(input: &HashMap<String, Module>) -> usize {
                 if let Some(m) = modules.get_mut(&k) {
                     next.extend(m.process(&f, v));
                 } else {
-                    // println!("output: {k}, {f}, {v:?}");
+                    trace!(output = k, "pulse left the network");
                 }
             }
             std::mem::swap(&mut signals, &mut next)
@@ -140,10 +229,13 @@ fn part1(input: &HashMap<String, Module>) -> usize {
     high * low
 }
 
+/// The modules feeding `zh`, which feeds `rx`.
+const PART2_INPUTS: [&str; 4] = ["vd", "ns", "bh", "dl"];
+
 #[aoc(day20, part2)]
 fn part2(input: &HashMap<String, Module>) -> usize {
     let mut modules = input.clone();
-    let inputs = ["vd", "ns", "bh", "dl"];
+    let inputs = PART2_INPUTS;
     let mut periods = HashMap::<String, usize>::new();
     let mut watching = HashSet::new();
     watching.extend(inputs);
@@ -155,10 +247,11 @@ fn part2(input: &HashMap<String, Module>) -> usize {
         let mut next = Vec::new();
         while !signals.is_empty() {
             for (k, f, v) in signals.drain(..) {
-                // println!("{f} -> {v} -> {k}");
+                trace!(from = f, to = k, high = v, "pulse");
                 if watching.contains(f.as_str()) && k == "zh" && v {
                     if let Some(last) = periods.get(f.as_str()) {
-                        periods.insert(f.clone(), last - n);
+                        debug!(module = f, period = n - last, "high pulses into zh repeat");
+                        periods.insert(f.clone(), n - last);
                         watching.remove(f.as_str());
                     } else {
                         periods.insert(f.clone(), n);
@@ -183,41 +276,3 @@ fn part2(input: &HashMap<String, Module>) -> usize {
         .reduce(num::integer::lcm)
         .expect("lcm")
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example1() {
-        assert_eq!(
-            part1(&parse(
-                "broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a"
-            )),
-            32000000
-        );
-    }
-
-    #[test]
-    fn part1_example2() {
-        assert_eq!(
-            part1(&parse(
-                "broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output"
-            )),
-            11687500
-        );
-    }
-
-    #[test]
-    fn part2_example() {
-        assert_eq!(part2(&parse("<EXAMPLE>")), 8008);
-    }
-}
@@ -0,0 +1,219 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    geometry::Point,
+    grid::Grid,
+    parse::{ParseError, Source},
+    search,
+    solution::Solution,
+    trace::debug,
+    validate::{self, Finding},
+};
+
+const PART1_STEPS: usize = 64;
+const PART2_STEPS: usize = 26_501_365;
+
+pub struct Garden {
+    /// whether each tile is a garden plot rather than a rock
+    plots: Grid<bool>,
+    start: Point,
+}
+
+impl Garden {
+    /// Whether `p` is a plot, with the map repeating forever in every direction.
+    fn is_plot(&self, p: Point) -> bool {
+        let (width, height) = (self.plots.width() as i64, self.plots.height() as i64);
+        self.plots[Point::new(p.x.rem_euclid(width), p.y.rem_euclid(height))]
+    }
+}
+
+#[aoc_generator(day21)]
+fn parse(input: &str) -> Result<Garden, ParseError> {
+    let src = Source::new(21, input);
+    let grid = Grid::try_parse_with(&src, "'.', '#' or 'S'", |c| {
+        matches!(c, '.' | '#' | 'S').then_some(c)
+    })?;
+    let Some((start, _)) = grid.iter().find(|(_, &c)| c == 'S') else {
+        return Err(src.error(src.end(), "a starting plot 'S'"));
+    };
+    Ok(Garden {
+        plots: grid.map(|&c| c != '#'),
+        start,
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(21, input);
+    let mut findings = validate::grid(&src, src.lines(), "'.', '#' or 'S'", |c| {
+        matches!(c, '.' | '#' | 'S')
+    });
+    let starts = src
+        .lines()
+        .flat_map(|line| line.match_indices('S').map(|(i, _)| &line[i..]))
+        .collect::<Vec<_>>();
+    if starts.is_empty() {
+        findings.push(Finding::at(&src, src.end(), "no starting plot 'S'"));
+    }
+    for at in starts.iter().skip(1) {
+        findings.push(Finding::at(&src, at, "more than one starting plot 'S'"));
+    }
+    if !findings.is_empty() {
+        return findings;
+    }
+    let Ok(Garden { plots, start }) = parse(input) else {
+        return findings;
+    };
+
+    // part 2 counts whole tiles at a time, which only works if the start is in the middle of a
+    // square map and the paths straight out from it are clear
+    let lines = src.lines().collect::<Vec<_>>();
+    let size = plots.width();
+    if plots.height() != size {
+        findings.push(Finding::at(
+            &src,
+            lines[0],
+            format!("map is {size}x{}, part 2 needs it square", plots.height()),
+        ));
+    }
+    let middle = Point::new(size as i64 / 2, plots.height() as i64 / 2);
+    if start != middle {
+        findings.push(Finding::at(
+            &src,
+            lines[start.y as usize],
+            format!("start is at {start}, part 2 needs it in the middle at {middle}"),
+        ));
+    } else if plots.row(start.y as usize).contains(&false)
+        || plots.col(start.x as usize).any(|p| !p)
+    {
+        findings.push(Finding::at(
+            &src,
+            lines[start.y as usize],
+            "part 2 needs the start's row and column clear of rocks",
+        ));
+    }
+    findings
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day21, part1)]
+fn part1(garden: &Garden) -> usize {
+    reachable(garden, PART1_STEPS, false)
+}
+
+#[aoc(day21, part2)]
+fn part2(garden: &Garden) -> usize {
+    reachable_tiled(garden, PART2_STEPS)
+}
+
+/// Plots that can be the last of exactly `steps` steps, optionally letting the map repeat.
+///
+/// Stepping back and forth means any plot reached in fewer steps of the same parity counts too.
+#[cfg_attr(
+    feature = "trace",
+    tracing::instrument(level = "debug", skip(garden), ret)
+)]
+fn reachable(garden: &Garden, steps: usize, tiled: bool) -> usize {
+    let dists = search::bfs_within(garden.start, steps, |&p| {
+        p.neighbors4()
+            .filter(|&n| (tiled || garden.plots.contains(n)) && garden.is_plot(n))
+            .collect::<Vec<_>>()
+    });
+    dists.values().filter(|&&d| d % 2 == steps % 2).count()
+}
+
+/// [`reachable`] on the repeating map for step counts too large to search.
+///
+/// With the start in the middle of a square map and clear paths out from it, each further
+/// map-width of steps reaches one more ring of whole tiles, so the count is quadratic in the
+/// number of rings. Three searches fix the quadratic.
+fn reachable_tiled(garden: &Garden, steps: usize) -> usize {
+    let size = garden.plots.width();
+    let (rings, rest) = (steps / size, steps % size);
+    if rings < 3 {
+        return reachable(garden, steps, true);
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|n| reachable(garden, rest + n * size, true));
+    debug!(rings, rest, a, b, c, "extrapolating");
+    // Newton's forward differences, evaluated at the number of rings
+    let (d1, d2) = (b - a, c + a - 2 * b);
+    a + d1 * rings + d2 * rings * (rings - 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day21/example.txt");
+
+    #[test]
+    fn example_steps() {
+        let garden = parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(16, reachable(&garden, 6, false));
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(plots, reachable(&garden, steps, true), "{steps} steps");
+        }
+    }
+
+    #[test]
+    fn tiled_extrapolation() {
+        // the example's start row and column aren't clear, so use a map where they are
+        let garden = parse(
+            ".......
+.#...#.
+..#.#..
+...S...
+..#....
+.#...#.
+.......",
+        )
+        .unwrap();
+        for steps in [24, 31, 40, 52] {
+            assert_eq!(
+                reachable(&garden, steps, true),
+                reachable_tiled(&garden, steps),
+                "{steps} steps"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_reports_part2_assumptions() {
+        let messages = |input| {
+            validate(input)
+                .into_iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert!(messages(".....\n.#.#.\n..S..\n.#.#.\n.....").is_empty());
+        assert_eq!(
+            vec!["day 21, line 2: start is at (1, 1), part 2 needs it in the middle at (2, 2)"],
+            messages(".....\n.S.#.\n.....\n.#.#.\n.....")
+        );
+        assert_eq!(
+            vec!["day 21, line 3: part 2 needs the start's row and column clear of rocks"],
+            messages(".....\n.#.#.\n..S#.\n.#.#.\n.....")
+        );
+    }
+}
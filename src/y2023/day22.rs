@@ -0,0 +1,272 @@
+use std::collections::VecDeque;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    geometry::Point,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+/// The cubes from `lo` to `hi` inclusive, as `[x, y, z]` with `lo` the smaller on every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    pub lo: [usize; 3],
+    pub hi: [usize; 3],
+}
+
+impl Brick {
+    /// The `(x, y)` of every cube in the brick's bottom layer.
+    fn footprint(&self) -> impl Iterator<Item = Point> {
+        let [x0, y0, _] = self.lo;
+        let [x1, y1, _] = self.hi;
+        (x0..=x1)
+            .cartesian_product(y0..=y1)
+            .map(|(x, y)| Point::new(x as i64, y as i64))
+    }
+
+    fn overlaps(&self, other: &Brick) -> bool {
+        (0..3).all(|axis| self.lo[axis] <= other.hi[axis] && other.lo[axis] <= self.hi[axis])
+    }
+}
+
+/// Which bricks rest directly on which, by their index in the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Supports {
+    above: Vec<Vec<usize>>,
+    below: Vec<Vec<usize>>,
+}
+
+impl Supports {
+    pub fn len(&self) -> usize {
+        self.above.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.above.is_empty()
+    }
+
+    /// The bricks resting directly on `brick`.
+    pub fn holds_up(&self, brick: usize) -> &[usize] {
+        &self.above[brick]
+    }
+
+    /// The bricks `brick` rests directly on, none if it's on the ground.
+    pub fn resting_on(&self, brick: usize) -> &[usize] {
+        &self.below[brick]
+    }
+
+    /// Whether `brick` can go without anything else falling.
+    pub fn is_safe(&self, brick: usize) -> bool {
+        self.above[brick]
+            .iter()
+            .all(|&above| self.below[above].len() > 1)
+    }
+
+    /// How many other bricks fall if `brick` goes, including those that lose every support in
+    /// the chain reaction.
+    pub fn falls_without(&self, brick: usize) -> usize {
+        let mut supports_left = self.below.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut queue = VecDeque::from([brick]);
+        let mut fallen = 0;
+        while let Some(gone) = queue.pop_front() {
+            for &above in &self.above[gone] {
+                supports_left[above] -= 1;
+                if supports_left[above] == 0 {
+                    fallen += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        fallen
+    }
+}
+
+/// The bricks once they've all fallen as far as they can, in the snapshot's order.
+pub struct Settled {
+    pub bricks: Vec<Brick>,
+    pub supports: Supports,
+}
+
+#[aoc_generator(day22)]
+fn parse(input: &str) -> Result<Settled, ParseError> {
+    let src = Source::new(22, input);
+    let bricks = src
+        .lines()
+        .map(|line| parse_brick(&src, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(settle(bricks))
+}
+
+fn parse_brick<'a>(src: &Source<'a>, line: &'a str) -> Result<Brick, ParseError> {
+    let (a, b) = src.split_once(line, "~")?;
+    let corner = |s: &'a str| -> Result<[usize; 3], ParseError> {
+        let mut coords = s.split(',');
+        let mut next = || src.parse(src.next(&mut coords, "a coordinate")?, "a coordinate");
+        Ok([next()?, next()?, next()?])
+    };
+    let (a, b) = (corner(a)?, corner(b)?);
+    Ok(Brick {
+        lo: [0, 1, 2].map(|axis| a[axis].min(b[axis])),
+        hi: [0, 1, 2].map(|axis| a[axis].max(b[axis])),
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(22, input);
+    let mut findings = Vec::new();
+    let lines = src.lines().collect::<Vec<_>>();
+    let bricks = validate::parse_each(
+        lines.iter().copied(),
+        |line| parse_brick(&src, line),
+        &mut findings,
+    );
+    if !findings.is_empty() {
+        return findings;
+    }
+
+    for (i, brick) in bricks.iter().enumerate() {
+        if brick.lo[2] == 0 {
+            findings.push(Finding::at(
+                &src,
+                lines[i],
+                "brick is in the ground, z must be at least 1",
+            ));
+        }
+        if let Some(j) = bricks[..i].iter().position(|other| brick.overlaps(other)) {
+            findings.push(Finding::at(
+                &src,
+                lines[i],
+                format!("brick overlaps the brick on line {}", j + 1),
+            ));
+        }
+    }
+    findings
+}
+
+/// Drops the bricks lowest first, keeping the top of each column and the brick that's there.
+fn settle(mut bricks: Vec<Brick>) -> Settled {
+    let width = bricks.iter().map(|b| b.hi[0] + 1).max().unwrap_or(0);
+    let depth = bricks.iter().map(|b| b.hi[1] + 1).max().unwrap_or(0);
+    let mut tops: Grid<(usize, Option<usize>)> = Grid::new(width, depth, (0, None));
+    let mut supports = Supports {
+        above: vec![Vec::new(); bricks.len()],
+        below: vec![Vec::new(); bricks.len()],
+    };
+
+    let order = (0..bricks.len())
+        .sorted_by_key(|&i| bricks[i].lo[2])
+        .collect_vec();
+    for i in order {
+        let brick = &mut bricks[i];
+        let rest = brick.footprint().map(|p| tops[p].0).max().unwrap_or(0);
+        let below = brick
+            .footprint()
+            .filter_map(|p| match tops[p] {
+                (top, Some(j)) if top == rest => Some(j),
+                _ => None,
+            })
+            .unique()
+            .collect_vec();
+
+        trace!(
+            brick = i,
+            from = brick.lo[2],
+            to = rest + 1,
+            ?below,
+            "falls"
+        );
+        let height = brick.hi[2] - brick.lo[2];
+        brick.lo[2] = rest + 1;
+        brick.hi[2] = rest + 1 + height;
+        for p in brick.footprint() {
+            tops[p] = (brick.hi[2], Some(i));
+        }
+        for &j in &below {
+            supports.above[j].push(i);
+        }
+        supports.below[i] = below;
+    }
+    for above in &mut supports.above {
+        above.sort_unstable();
+    }
+    Settled { bricks, supports }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+
+    type Input = Settled;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day22, part1)]
+fn part1(input: &Settled) -> usize {
+    let supports = &input.supports;
+    (0..supports.len()).filter(|&i| supports.is_safe(i)).count()
+}
+
+#[aoc(day22, part2)]
+fn part2(input: &Settled) -> usize {
+    let supports = &input.supports;
+    (0..supports.len()).map(|i| supports.falls_without(i)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day22/example.txt");
+
+    #[test]
+    fn support_graph() {
+        let Settled { bricks, supports } = parse(EXAMPLE.trim_end()).unwrap();
+        // A holds up B and C, which both hold up D and E, and so on up to G on F
+        assert_eq!([1, 2], supports.holds_up(0));
+        assert_eq!([3, 4], supports.holds_up(1));
+        assert_eq!([1, 2], supports.resting_on(3));
+        assert_eq!([5], supports.resting_on(6));
+        assert!(supports.resting_on(0).is_empty() && supports.holds_up(6).is_empty());
+        assert!(!supports.is_safe(0) && supports.is_safe(1) && !supports.is_safe(5));
+        assert_eq!(
+            (6, 1),
+            (supports.falls_without(0), supports.falls_without(5))
+        );
+        assert_eq!(([1, 0, 1], [1, 2, 1]), (bricks[0].lo, bricks[0].hi));
+        assert_eq!(5, bricks[6].lo[2]);
+    }
+
+    #[test]
+    fn validate_reports_bad_bricks() {
+        let findings = validate("0,0,1~0,2,1\n1,0,0~1,0,1\n0,1,3~0,1,1")
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "day 22, line 2: brick is in the ground, z must be at least 1",
+                "day 22, line 3: brick overlaps the brick on line 1",
+            ],
+            findings
+        );
+    }
+}
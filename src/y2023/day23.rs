@@ -0,0 +1,183 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    geometry::{Direction, Point},
+    graph::Graph,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::debug,
+    validate::{self, Finding},
+};
+
+const TILES: &str = "'#', '.', '^', '>', 'v' or '<'";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Forest,
+    Path,
+    Slope(Direction),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '#' => Tile::Forest,
+            '.' => Tile::Path,
+            '^' => Tile::Slope(Direction::Up),
+            '>' => Tile::Slope(Direction::Right),
+            'v' => Tile::Slope(Direction::Down),
+            '<' => Tile::Slope(Direction::Left),
+            _ => return Err(c),
+        })
+    }
+}
+
+pub struct Trails {
+    grid: Grid<Tile>,
+    start: Point,
+    end: Point,
+}
+
+impl Trails {
+    /// The map compressed to its junctions, only going down slopes if `slippery`.
+    pub fn graph(&self, slippery: bool) -> Graph<Point> {
+        Graph::compress(self.start, &[self.end], |&p| {
+            let dirs = match self.grid[p] {
+                Tile::Slope(dir) if slippery => vec![dir],
+                _ => Direction::ALL.to_vec(),
+            };
+            dirs.into_iter()
+                .filter_map(|dir| self.grid.step(p, dir))
+                .filter(|&n| self.grid[n] != Tile::Forest)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    fn longest_hike(&self, slippery: bool) -> usize {
+        let graph = self.graph(slippery);
+        debug!(slippery, junctions = graph.len(), "compressed the trails");
+        let start = graph.index_of(&self.start).expect("start");
+        let end = graph.index_of(&self.end).expect("no way to the end");
+        graph.longest_path(start, end).expect("no way to the end")
+    }
+}
+
+#[aoc_generator(day23)]
+fn parse(input: &str) -> Result<Trails, ParseError> {
+    let src = Source::new(23, input);
+    let grid = Grid::try_parse_with(&src, TILES, |c| Tile::try_from(c).ok())?;
+    let opening = |y: usize| {
+        let x = grid.row(y).iter().position(|&t| t == Tile::Path)?;
+        Some(Point::new(x as i64, y as i64))
+    };
+    let start = opening(0).ok_or_else(|| src.error(input, "a path in the top row"))?;
+    let end = opening(grid.height() - 1)
+        .ok_or_else(|| src.error(src.end(), "a path in the bottom row"))?;
+    Ok(Trails { grid, start, end })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(23, input);
+    let mut findings = validate::grid(&src, src.lines(), TILES, |c| Tile::try_from(c).is_ok());
+    let lines = src.lines().collect::<Vec<_>>();
+    for (name, line) in [("top", lines.first()), ("bottom", lines.last())] {
+        let Some(line) = line else {
+            continue;
+        };
+        let openings = line.matches('.').count();
+        if openings != 1 {
+            findings.push(Finding::at(
+                &src,
+                line,
+                format!("{name} row has {openings} paths, expected exactly one"),
+            ));
+        }
+    }
+    if !findings.is_empty() {
+        return findings;
+    }
+    let Ok(trails) = parse(input) else {
+        return findings;
+    };
+
+    let graph = trails.graph(false);
+    if graph.index_of(&trails.end).is_none() {
+        findings.push(Finding::at(
+            &src,
+            lines[lines.len() - 1],
+            "no way to the end",
+        ));
+    } else if graph.len() > 64 {
+        findings.push(Finding::at(
+            &src,
+            src.end(),
+            format!("{} junctions, the search handles at most 64", graph.len()),
+        ));
+    }
+    findings
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type Input = Trails;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day23, part1)]
+fn part1(trails: &Trails) -> usize {
+    trails.longest_hike(true)
+}
+
+#[aoc(day23, part2)]
+fn part2(trails: &Trails) -> usize {
+    trails.longest_hike(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day23/example.txt");
+
+    #[test]
+    fn example_graph() {
+        let trails = parse(EXAMPLE.trim_end()).unwrap();
+        // the start, the end, and the seven junctions between them
+        assert_eq!(9, trails.graph(false).len());
+        let slippery = trails.graph(true);
+        let start = slippery.index_of(&Point::new(1, 0)).unwrap();
+        assert_eq!(&[(1, 15)], slippery.edges(start));
+        assert_eq!(&Point::new(3, 5), slippery.node(1));
+    }
+
+    #[test]
+    fn validate_reports_missing_paths() {
+        let findings = validate("#.#\n#.#\n###")
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["day 23, line 3: bottom row has 0 paths, expected exactly one"],
+            findings
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+use num::{BigInt, BigRational, One, Signed, Zero};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::{debug, trace},
+    validate::{self, Finding},
+};
+
+const TEST_AREA: (i64, i64) = (200_000_000_000_000, 400_000_000_000_000);
+
+/// Coordinates as big integers, so products of them and sums of those products are exact.
+type Vec3 = [BigInt; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pos: [i64; 3],
+    vel: [i64; 3],
+}
+
+impl Hailstone {
+    fn pos(&self) -> Vec3 {
+        self.pos.map(BigInt::from)
+    }
+
+    fn vel(&self) -> Vec3 {
+        self.vel.map(BigInt::from)
+    }
+}
+
+#[aoc_generator(day24)]
+fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    let src = Source::new(24, input);
+    src.lines()
+        .map(|line| parse_hailstone(&src, line))
+        .collect()
+}
+
+fn parse_hailstone<'a>(src: &Source<'a>, line: &'a str) -> Result<Hailstone, ParseError> {
+    let (pos, vel) = src.split_once(line, "@")?;
+    let triple = |s: &'a str, expected: &str| -> Result<[i64; 3], ParseError> {
+        let mut parts = s.split(',').map(str::trim);
+        let mut next = || src.parse(src.next(&mut parts, expected)?, expected);
+        Ok([next()?, next()?, next()?])
+    };
+    Ok(Hailstone {
+        pos: triple(pos, "a position")?,
+        vel: triple(vel, "a velocity")?,
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(24, input);
+    let mut findings = Vec::new();
+    let hail = validate::parse_each(
+        src.lines(),
+        |line| parse_hailstone(&src, line),
+        &mut findings,
+    );
+    if !findings.is_empty() {
+        return findings;
+    }
+    if hail.len() < 3 {
+        findings.push(Finding::at(
+            &src,
+            src.end(),
+            "part 2 needs at least three hailstones",
+        ));
+    } else if throw(&hail).is_none() {
+        findings.push(Finding::at(
+            &src,
+            src.end(),
+            "couldn't find a throw from a whole-number position that hits every hailstone",
+        ));
+    }
+    findings
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+
+    type Input = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day24, part1)]
+fn part1(hail: &[Hailstone]) -> usize {
+    crossings_within(hail, TEST_AREA)
+}
+
+#[aoc(day24, part2)]
+fn part2(hail: &[Hailstone]) -> BigInt {
+    let (pos, _) = throw(hail).expect("no throw hits every hailstone");
+    pos.iter().sum()
+}
+
+/// How many pairs of hailstones' paths cross in the future, ignoring z, within `lo..=hi` on
+/// both x and y.
+fn crossings_within(hail: &[Hailstone], (lo, hi): (i64, i64)) -> usize {
+    let (lo, hi) = (BigInt::from(lo), BigInt::from(hi));
+    hail.iter()
+        .tuple_combinations()
+        .filter(|(a, b)| {
+            let (a_pos, a_vel, b_vel) = (a.pos(), a.vel(), b.vel());
+            // a.pos + a.vel * t == b.pos + b.vel * u, by Cramer's rule, with t and u over det
+            let [dx, dy, _] = sub(&b.pos(), &a_pos);
+            let mut det = &b_vel[0] * &a_vel[1] - &a_vel[0] * &b_vel[1];
+            if det.is_zero() {
+                return false;
+            }
+            let mut t = &b_vel[0] * &dy - &b_vel[1] * &dx;
+            let mut u = &a_vel[0] * &dy - &a_vel[1] * &dx;
+            // compare everything multiplied by det, so flip them all if it's negative
+            if det.is_negative() {
+                (det, t, u) = (-det, -t, -u);
+            }
+            let x = &a_pos[0] * &det + &a_vel[0] * &t;
+            let y = &a_pos[1] * &det + &a_vel[1] * &t;
+            trace!(%det, %t, %u, %x, %y, "paths cross");
+            let area = &lo * &det..=&hi * &det;
+            !t.is_negative() && !u.is_negative() && area.contains(&x) && area.contains(&y)
+        })
+        .count()
+}
+
+/// The position and velocity of a rock thrown to hit every hailstone.
+///
+/// For a rock at `p` moving at `v` to hit a hailstone `h`, `p - h.pos` and `v - h.vel` must be
+/// parallel, so `(p - h.pos) × (v - h.vel) = 0`. The `p × v` in that is the same for every
+/// hailstone, so subtracting it for two pairs of hailstones leaves six linear equations.
+fn throw(hail: &[Hailstone]) -> Option<(Vec3, Vec3)> {
+    // almost any three hailstones will do, but some may be parallel, so move along until three
+    // pin the throw down
+    hail.iter().tuple_windows().find_map(|(a, b, c)| {
+        let (a_pos, a_vel) = (a.pos(), a.vel());
+        let mut rows = Vec::new();
+        for other in [b, c] {
+            let (o_pos, o_vel) = (other.pos(), other.vel());
+            // p × (o.vel - a.vel) + (o.pos - a.pos) × v = o.pos × o.vel - a.pos × a.vel
+            let w = sub(&o_vel, &a_vel);
+            let d = sub(&o_pos, &a_pos);
+            let rhs = sub(&cross(&o_pos, &o_vel), &cross(&a_pos, &a_vel));
+            let zero = BigInt::zero;
+            let p_coeffs = [
+                [zero(), w[2].clone(), -&w[1]],
+                [-&w[2], zero(), w[0].clone()],
+                [w[1].clone(), -&w[0], zero()],
+            ];
+            let v_coeffs = [
+                [zero(), -&d[2], d[1].clone()],
+                [d[2].clone(), zero(), -&d[0]],
+                [-&d[1], d[0].clone(), zero()],
+            ];
+            for ((p, v), r) in p_coeffs.into_iter().zip(v_coeffs).zip(rhs) {
+                let mut row = p.to_vec();
+                row.extend(v);
+                row.push(r);
+                rows.push(row);
+            }
+        }
+        let solution = solve(rows)?
+            .into_iter()
+            .map(|x| x.is_integer().then(|| x.to_integer()))
+            .collect::<Option<Vec<_>>>()?;
+        let [p0, p1, p2, v0, v1, v2] = <[BigInt; 6]>::try_from(solution).ok()?;
+        let (pos, vel) = ([p0, p1, p2], [v0, v1, v2]);
+        debug!(?pos, ?vel, "throw through three hailstones");
+        hail.iter()
+            .all(|h| hits(&pos, &vel, h))
+            .then_some((pos, vel))
+    })
+}
+
+/// Whether a rock thrown from `pos` at `vel` hits `h` at some time from now on.
+fn hits(pos: &Vec3, vel: &Vec3, h: &Hailstone) -> bool {
+    // pos + vel * t == h.pos + h.vel * t, so pos - h.pos == (h.vel - vel) * t
+    let (gap, closing) = (sub(pos, &h.pos()), sub(&h.vel(), vel));
+    let is_zero = |v: &Vec3| v.iter().all(Zero::is_zero);
+    if is_zero(&closing) {
+        is_zero(&gap)
+    } else {
+        let dot = gap.iter().zip(&closing).map(|(g, c)| g * c).sum::<BigInt>();
+        is_zero(&cross(&gap, &closing)) && !dot.is_negative()
+    }
+}
+
+/// Solves the linear equations in `rows`, each its coefficients followed by the constant, by
+/// Gauss-Jordan elimination. `None` if they don't have exactly one solution.
+fn solve(rows: Vec<Vec<BigInt>>) -> Option<Vec<BigRational>> {
+    let mut rows = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(BigRational::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+        let scale = BigRational::one() / &rows[col][col];
+        for x in &mut rows[col] {
+            *x *= &scale;
+        }
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[col].clone();
+            if r == col || factor.is_zero() {
+                continue;
+            }
+            for (x, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *x -= &factor * p;
+            }
+        }
+    }
+    Some(rows.into_iter().map(|mut row| row.pop().unwrap()).collect())
+}
+
+fn sub(a: &Vec3, b: &Vec3) -> Vec3 {
+    [&a[0] - &b[0], &a[1] - &b[1], &a[2] - &b[2]]
+}
+
+fn cross(a: &Vec3, b: &Vec3) -> Vec3 {
+    [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day24/example.txt");
+
+    #[test]
+    fn example_crossings() {
+        let hail = parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(2, crossings_within(&hail, (7, 27)));
+    }
+
+    #[test]
+    fn example_throw() {
+        let hail = parse(EXAMPLE.trim_end()).unwrap();
+        let big = |v: [i64; 3]| v.map(BigInt::from);
+        assert_eq!(Some((big([24, 13, 10]), big([-3, 1, 2]))), throw(&hail));
+        assert!(!hits(&big([24, 13, 10]), &big([-3, 1, 3]), &hail[0]));
+        assert_eq!(BigInt::from(47), part2(&hail));
+    }
+
+    #[test]
+    fn throws_past_a_run_of_unhelpful_hailstones() {
+        let example = parse(EXAMPLE.trim_end()).unwrap();
+        // any three hailstones including two of these leave the throw undetermined
+        let mut hail = vec![example[0]; 120];
+        hail.extend(&example[1..]);
+        assert_eq!(throw(&example), throw(&hail));
+    }
+
+    #[test]
+    fn rejects_coordinates_past_i64() {
+        let err = parse("123456789012345678901, 1, 1 @ 1, 1, 1")
+            .err()
+            .unwrap();
+        assert_eq!(
+            "day 24, line 1, column 1: expected a position, found \"123456789012345678901, 1, 1 @ 1, 1, 1\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    graph::Graph,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::debug,
+    validate::{self, Finding},
+};
+
+/// How many wires part 1 says to disconnect.
+const WIRES_TO_CUT: usize = 3;
+
+#[aoc_generator(day25)]
+fn parse(input: &str) -> Result<Graph<String>, ParseError> {
+    let src = Source::new(25, input);
+    let mut wires = Vec::new();
+    for line in src.lines() {
+        wires.extend(parse_line(&src, line)?);
+    }
+    Ok(Graph::undirected(wires))
+}
+
+/// The wires from the component named at the start of `line` to each of the others.
+fn parse_line<'a>(src: &Source<'a>, line: &'a str) -> Result<Vec<(String, String)>, ParseError> {
+    let (from, to) = src.split_once(line, ": ")?;
+    let component = |name: &'a str| {
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) {
+            Ok(name.to_string())
+        } else {
+            Err(src.error(name, "a component name"))
+        }
+    };
+    let from = component(from)?;
+    let mut to = to.split_whitespace().peekable();
+    if to.peek().is_none() {
+        return Err(src.error(line, "at least one connected component"));
+    }
+    to.map(|name| Ok((from.clone(), component(name)?)))
+        .collect()
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(25, input);
+    let mut findings = Vec::new();
+    validate::parse_each(src.lines(), |line| parse_line(&src, line), &mut findings);
+    if !findings.is_empty() {
+        return findings;
+    }
+    let Ok(graph) = parse(input) else {
+        return findings;
+    };
+
+    match graph.min_cut() {
+        None => findings.push(Finding::at(
+            &src,
+            src.end(),
+            "needs at least two components to split",
+        )),
+        Some((cut, _)) if cut != WIRES_TO_CUT => findings.push(Finding::at(
+            &src,
+            src.end(),
+            format!("the fewest wires that split the components is {cut}, expected {WIRES_TO_CUT}"),
+        )),
+        Some(_) => {}
+    }
+    findings
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+
+    type Input = Graph<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    /// There's no part 2 on the last day, just a star for having all the others.
+    fn part2(_: &Self::Input) -> Answer {
+        "Merry Christmas!".into()
+    }
+}
+
+#[aoc(day25, part1)]
+fn part1(graph: &Graph<String>) -> usize {
+    let (cut, side) = graph.min_cut().expect("nothing to split");
+    debug!(cut, side = side.len(), components = graph.len(), "min cut");
+    assert_eq!(WIRES_TO_CUT, cut, "wrong number of wires to cut");
+    side.len() * (graph.len() - side.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day25/example.txt");
+
+    #[test]
+    fn example_cut() {
+        let graph = parse(EXAMPLE.trim_end()).unwrap();
+        let (cut, side) = graph.min_cut().unwrap();
+        let mut names = side
+            .iter()
+            .map(|&i| graph.node(i).as_str())
+            .collect::<Vec<_>>();
+        names.sort();
+        let smaller = [
+            "cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs",
+        ];
+        let larger = ["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"];
+        assert_eq!(3, cut);
+        assert!(names == smaller || names == larger, "{names:?}");
+    }
+
+    #[test]
+    fn validate_reports_wrong_cut() {
+        let findings = validate("a: b c\nb: c\nc: d")
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["day 25, line 3: the fewest wires that split the components is 1, expected 3"],
+            findings
+        );
+    }
+}
@@ -2,24 +2,33 @@ use std::collections::HashMap;
 
 use aoc_runner_derive::{aoc, aoc_generator};
 
-type GridNums = (HashMap<(i32, i32), char>, Vec<(u32, Vec<(i32, i32)>)>);
+use crate::{
+    answer::Answer,
+    geometry::Point,
+    grid::Grid,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
+type GridNums = (Grid<char>, Vec<(u32, Vec<Point>)>);
 
 #[aoc_generator(day3)]
-fn generator(input: &str) -> GridNums {
-    let mut grid = HashMap::new();
+fn generator(input: &str) -> Result<GridNums, ParseError> {
+    let grid = Grid::try_parse_with(&Source::new(3, input), "any char", Some)?;
     let mut numbers = Vec::new();
-    for (row, line) in input.lines().enumerate() {
+    for (row, line) in grid.rows().enumerate() {
         let mut current_number = None;
         let mut current_number_coords = Vec::new();
-        for (col, char) in line.char_indices() {
-            grid.insert((col as i32, row as i32), char);
+        for (col, char) in line.iter().enumerate() {
             if let Some(digit) = char.to_digit(10) {
                 if let Some(num) = current_number {
                     current_number = Some((num * 10) + digit)
                 } else {
                     current_number = Some(digit)
                 }
-                current_number_coords.push((col as i32, row as i32));
+                current_number_coords.push(Point::new(col as i64, row as i64));
             } else if let Some(num) = current_number.take() {
                 numbers.push((num, std::mem::take(&mut current_number_coords)));
             }
@@ -29,7 +38,33 @@ fn generator(input: &str) -> GridNums {
         }
     }
 
-    (grid, numbers)
+    Ok((grid, numbers))
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(3, input);
+    validate::grid(&src, src.lines(), "any char", |_| true)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = GridNums;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
 
 #[aoc(day3, part1)]
@@ -39,21 +74,10 @@ fn part1((grid, numbers): &GridNums) -> u32 {
         .filter_map(|(num, coords)| {
             let touches_symbol = coords
                 .iter()
-                .flat_map(|&(x, y)| {
-                    [
-                        (x, y + 1),
-                        (x + 1, y + 1),
-                        (x + 1, y),
-                        (x + 1, y - 1),
-                        (x, y - 1),
-                        (x - 1, y - 1),
-                        (x - 1, y),
-                        (x - 1, y + 1),
-                    ]
-                })
-                .filter(|coord| grid.contains_key(coord))
+                .flat_map(|&coord| grid.neighbors8(coord))
                 .filter(|coord| !coords.contains(coord))
-                .any(|coord| !grid[&coord].is_ascii_digit() && (grid[&coord] != '.'));
+                .any(|coord| !grid[coord].is_ascii_digit() && (grid[coord] != '.'));
+            trace!(num, touches_symbol, "at {}", coords[0]);
 
             if touches_symbol {
                 Some(num)
@@ -66,26 +90,14 @@ fn part1((grid, numbers): &GridNums) -> u32 {
 
 #[aoc(day3, part2)]
 fn part2((grid, numbers): &GridNums) -> u32 {
-    let mut gears: HashMap<(i32, i32), Vec<(u32, Vec<_>)>> = HashMap::new();
+    let mut gears: HashMap<Point, Vec<(u32, Vec<_>)>> = HashMap::new();
     for (num, coords) in numbers {
         let neighbors = coords
             .iter()
-            .flat_map(|&(x, y)| {
-                [
-                    (x, y + 1),
-                    (x + 1, y + 1),
-                    (x + 1, y),
-                    (x + 1, y - 1),
-                    (x, y - 1),
-                    (x - 1, y - 1),
-                    (x - 1, y),
-                    (x - 1, y + 1),
-                ]
-            })
-            .filter(|coord| grid.contains_key(coord))
+            .flat_map(|&coord| grid.neighbors8(coord))
             .filter(|coord| !coords.contains(coord));
         for coord in neighbors {
-            if grid[&coord] == '*' {
+            if grid[coord] == '*' {
                 let entry = gears.entry(coord).or_default();
                 entry.push((*num, coords.clone()));
             }
@@ -101,6 +113,9 @@ fn part2((grid, numbers): &GridNums) -> u32 {
         .values_mut()
         .map(|nums| nums.iter().map(|t| t.0).collect::<Vec<_>>())
         .filter(|nums| nums.len() == 2)
-        .map(|nums| nums[0] * nums[1])
+        .map(|nums| {
+            trace!(?nums, "gear");
+            nums[0] * nums[1]
+        })
         .sum()
 }
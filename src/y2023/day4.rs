@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::Finding,
+};
+
+/// How many winning numbers each card has.
+#[aoc_generator(day4)]
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let src = Source::new(4, input);
+    src.lines().map(|line| parse_card(&src, line)).collect()
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(4, input);
+    let mut findings = Vec::new();
+    let lines = src.lines().collect::<Vec<_>>();
+    // part 2 wins copies of the cards after each one, which have to exist
+    for (i, &line) in lines.iter().enumerate() {
+        let matches = match parse_card(&src, line) {
+            Ok(matches) => matches,
+            Err(err) => {
+                findings.push(err.into());
+                continue;
+            }
+        };
+        let remaining = lines.len() - i - 1;
+        if matches > remaining {
+            findings.push(Finding::at(
+                &src,
+                line,
+                format!("{matches} matches but only {remaining} cards follow"),
+            ));
+        }
+    }
+    findings
+}
+
+/// How many different numbers on a card are winning numbers, counting each only once however
+/// often it's listed.
+fn parse_card<'a>(src: &Source<'a>, line: &'a str) -> Result<usize, ParseError> {
+    let (card, rest) = src.split_once(line, ":")?;
+    let num = src.strip_prefix(card, "Card")?;
+    src.parse::<u32>(num.trim(), "a card number")?;
+
+    let (winning, have) = src.split_once(rest, "|")?;
+    let winning = winning
+        .split_whitespace()
+        .map(|s| src.parse(s, "a winning number"))
+        .collect::<Result<HashSet<u32>, _>>()?;
+    let have = have
+        .split_whitespace()
+        .map(|s| src.parse(s, "a number"))
+        .collect::<Result<HashSet<u32>, _>>()?;
+    Ok(have.iter().filter(|n| winning.contains(n)).count())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day4, part1)]
+fn part1(input: &[usize]) -> usize {
+    input
+        .iter()
+        .map(|&matches| match matches {
+            0 => 0,
+            n => 2usize.pow(n as u32 - 1),
+        })
+        .sum()
+}
+
+#[aoc(day4, part2)]
+fn part2(input: &[usize]) -> usize {
+    let mut copies = vec![1; input.len()];
+    for (i, &matches) in input.iter().enumerate() {
+        let card_copies = copies[i];
+        trace!(card = i + 1, copies = card_copies, matches);
+        // validate reports cards that win copies past the end of the table, which don't exist
+        let won = (i + 1 + matches).min(copies.len());
+        for copy in &mut copies[i + 1..won] {
+            *copy += card_copies;
+        }
+    }
+    copies.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_repeated_numbers_once() {
+        let card = "Card 1: 5 7 | 5 5 7 9";
+        assert_eq!(Ok(2), parse_card(&Source::new(4, card), card));
+        // part 1 doesn't mind a card winning copies of cards that aren't there
+        assert_eq!(2, part1(&parse(card).unwrap()));
+        assert_eq!(1, part2(&parse(card).unwrap()));
+        assert_eq!(1, validate(card).len());
+        let cards = parse(&format!("{card}\nCard 2: 1 | 2\nCard 3: 1 | 2")).unwrap();
+        assert_eq!(2, part1(&cards));
+        assert_eq!(5, part2(&cards));
+    }
+}
@@ -0,0 +1,972 @@
+use std::{error::Error, fmt, ops};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    search,
+    solution::{Alternative, Solution},
+    trace::{debug, trace},
+    validate::Finding,
+};
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+pub struct Input {
+    seeds: Vec<u64>,
+    /// in the order they appear, which needn't be the order they're applied in
+    maps: Vec<Map>,
+    /// indices into `maps` of those that lead from seed to location, in the order to apply them
+    chain: Vec<usize>,
+}
+
+/// Why numbers in one category can't be converted to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvertError {
+    UnknownCategory(String),
+    NoPath {
+        from: String,
+        to: String,
+    },
+    /// The maps only lead the other way, and more than one number maps to the same place.
+    NotInvertible {
+        from: String,
+        to: String,
+    },
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::UnknownCategory(category) => {
+                write!(f, "no map goes to or from {category:?}")
+            }
+            ConvertError::NoPath { from, to } => {
+                write!(f, "no maps lead from {from:?} to {to:?} or back")
+            }
+            ConvertError::NotInvertible { from, to } => write!(
+                f,
+                "the maps from {to:?} to {from:?} can't be undone, some numbers end up the same"
+            ),
+        }
+    }
+}
+
+impl Error for ConvertError {}
+
+impl Input {
+    /// Every map from seed to location as a single function.
+    pub fn composed(&self) -> Piecewise {
+        compose(self.chain())
+    }
+
+    /// The maps from seed to location, in the order to apply them.
+    fn chain(&self) -> impl Iterator<Item = &Map> {
+        self.chain.iter().map(|&i| &self.maps[i])
+    }
+
+    /// A single function from numbers in category `from` to those in `to`, through whichever
+    /// maps lead from one to the other, or back through them if they only go the other way.
+    pub fn converter(&self, from: &str, to: &str) -> Result<Piecewise, ConvertError> {
+        let maps = |indices: Vec<usize>| indices.into_iter().map(|i| &self.maps[i]);
+        match self.maps_between(from, to) {
+            Ok(indices) => Ok(compose(maps(indices))),
+            Err(ConvertError::NoPath { .. }) => {
+                let Ok(back) = self.maps_between(to, from) else {
+                    return Err(ConvertError::NoPath {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    });
+                };
+                compose(maps(back))
+                    .inverse()
+                    .ok_or_else(|| ConvertError::NotInvertible {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// The indices of the fewest maps that lead from category `from` to `to`, in the order to
+    /// apply them.
+    fn maps_between(&self, from: &str, to: &str) -> Result<Vec<usize>, ConvertError> {
+        for category in [from, to] {
+            if !self
+                .maps
+                .iter()
+                .any(|m| m.from == category || m.to == category)
+            {
+                return Err(ConvertError::UnknownCategory(category.to_string()));
+            }
+        }
+        let (_, categories) = search::bfs(
+            from,
+            |&category| {
+                self.maps
+                    .iter()
+                    .filter(move |m| m.from == category)
+                    .map(|m| m.to.as_str())
+            },
+            |&category| category == to,
+        )
+        .ok_or_else(|| ConvertError::NoPath {
+            from: from.to_string(),
+            to: to.to_string(),
+        })?;
+        Ok(categories
+            .windows(2)
+            .map(|pair| {
+                self.maps
+                    .iter()
+                    .position(|m| m.from == pair[0] && m.to == pair[1])
+                    .expect("a map between neighbouring categories")
+            })
+            .collect())
+    }
+
+    /// Every number `seed` has on its way to a location.
+    pub fn provenance(&self, seed: u64) -> Provenance {
+        let mut steps = vec![Step {
+            category: SEED.to_string(),
+            value: seed,
+            by: None,
+        }];
+        let mut n = seed;
+        for map in self.chain() {
+            let by = map.matching(n);
+            n = by.and_then(|range| range.apply(n)).unwrap_or(n);
+            steps.push(Step {
+                category: map.to.clone(),
+                value: n,
+                by: by.cloned(),
+            });
+        }
+        Provenance { steps }
+    }
+
+    /// The seed listed in the first line that ends up at the lowest location.
+    pub fn lowest(&self) -> Provenance {
+        let composed = self.composed();
+        let seed = self
+            .seeds
+            .iter()
+            .copied()
+            .min_by_key(|&seed| composed.apply(seed))
+            .expect("seeds");
+        self.provenance(seed)
+    }
+
+    /// The lowest seed in the ranges listed in the first line out of those that end up at the
+    /// lowest location.
+    pub fn lowest_in_ranges(&self) -> Provenance {
+        let composed = self.composed();
+        let location = self
+            .seed_ranges()
+            .flat_map(|seeds| composed.image(seeds))
+            .map(|range| range.start)
+            .min()
+            .expect("seeds");
+        let seed = composed
+            .preimage(location..location + 1)
+            .into_iter()
+            .flat_map(|candidates| {
+                self.seed_ranges().filter_map(move |seeds| {
+                    let start = seeds.start.max(candidates.start);
+                    (start < seeds.end.min(candidates.end)).then_some(start)
+                })
+            })
+            .min()
+            .expect("a seed ends up at the lowest location");
+        self.provenance(seed)
+    }
+
+    fn seed_ranges(&self) -> impl Iterator<Item = ops::Range<u64>> + '_ {
+        self.seeds.chunks(2).map(|chunk| {
+            assert_eq!(2, chunk.len());
+            chunk[0]..chunk[0] + chunk[1]
+        })
+    }
+}
+
+#[aoc_generator(day5)]
+fn generator(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(5, input);
+    let mut lines = src.lines();
+    let seeds = src.next(&mut lines, "a list of seeds")?;
+    let numbers = src
+        .strip_prefix(seeds, "seeds:")?
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let seeds = numbers
+        .iter()
+        .map(|s| src.parse::<u64>(s, "a seed number"))
+        .collect::<Result<Vec<_>, _>>()?;
+    // part 2 reads the seeds as start and length pairs
+    for (pair, numbers) in seeds.chunks(2).zip(numbers.chunks(2)) {
+        if let [start, length] = pair {
+            if start.checked_add(*length).is_none() {
+                return Err(src.error(numbers[0], "a seed range that ends within u64"));
+            }
+        }
+    }
+    let blank = src.next(&mut lines, "a blank line")?;
+    if !blank.trim().is_empty() {
+        return Err(src.error(blank, "a blank line"));
+    }
+
+    let mut maps = Vec::new();
+    let mut headers = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        } else if line.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (name, _rest) = src.split_once(line, " ")?;
+            let (from, to) = src.split_once(name, "-to-")?;
+            maps.push(Map::new(from, to, Vec::new()));
+            headers.push(line);
+        } else {
+            let Some(map) = maps.last_mut() else {
+                return Err(src.error(line, "a map header"));
+            };
+            let parsed = line
+                .split_whitespace()
+                .map(|s| src.parse::<u64>(s, "a range number"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let [dest_start, source_start, length] = parsed[..] else {
+                return Err(src.error(line, "three range numbers"));
+            };
+            if dest_start.max(source_start).checked_add(length).is_none() {
+                return Err(src.error(line, "a range that ends within u64"));
+            }
+            map.ranges.push(Range {
+                line: src.locate(line).0,
+                ..Range::new(dest_start, source_start, length)
+            });
+        }
+    }
+
+    // following the maps from seed has to lead to location, without branching or leaving any
+    // map out
+    let reachable = search::bfs_reachable(SEED, |&category| {
+        maps.iter()
+            .filter(move |m| m.from == category)
+            .map(|m| m.to.as_str())
+    });
+    for (i, (map, header)) in maps.iter().zip(&headers).enumerate() {
+        if maps[..i].iter().any(|m| m.from == map.from) {
+            return Err(src.error(header, format!("the only map from {:?}", map.from)));
+        }
+        if !reachable.contains_key(map.from.as_str()) {
+            return Err(src.error(
+                header,
+                format!("a map from {SEED:?} or a category it leads to"),
+            ));
+        }
+    }
+    let mut almanac = Input {
+        seeds,
+        maps,
+        chain: Vec::new(),
+    };
+    almanac.chain = almanac
+        .maps_between(SEED, LOCATION)
+        .map_err(|_| src.error(src.end(), format!("a map to {LOCATION:?}")))?;
+    Ok(almanac)
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let almanac = match generator(input) {
+        Ok(almanac) => almanac,
+        Err(err) => return vec![err.into()],
+    };
+    let src = Source::new(5, input);
+    let mut findings = Vec::new();
+    if almanac.seeds.len() % 2 != 0 {
+        findings.push(Finding::at(
+            &src,
+            src.lines().next().unwrap_or(src.end()),
+            "odd number of seeds, part 2 reads them as start and length pairs",
+        ));
+    }
+    findings
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Input;
+
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[
+        Alternative {
+            part: 1,
+            name: "composed",
+            solve: |input| part1_composed(input).into(),
+        },
+        Alternative {
+            part: 2,
+            name: "composed",
+            solve: |input| part2_composed(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    /// The winning seed and the number it has in each category on the way to its location.
+    fn explain(input: &Self::Input, part: u8) -> Option<String> {
+        let lowest = match part {
+            1 => input.lowest(),
+            _ => input.lowest_in_ranges(),
+        };
+        Some(lowest.to_string())
+    }
+}
+
+#[aoc(day5, part1)]
+fn part1(input: &Input) -> u64 {
+    let mut min = u64::MAX;
+    for seed in input.seeds.iter() {
+        let mut n = *seed;
+        for map in input.chain() {
+            n = map.apply(n);
+        }
+        if n < min {
+            debug!(seed, location = n, "new lowest location");
+            min = n;
+        }
+    }
+    min
+}
+
+#[aoc(day5, part2)]
+fn part2(input: &Input) -> u64 {
+    let mut ranges = input.seed_ranges().collect::<Vec<_>>();
+    for map in input.chain() {
+        ranges = ranges
+            .into_iter()
+            .flat_map(|range| map.apply_range(range))
+            .collect();
+        trace!(?ranges);
+    }
+    debug!(ranges = ranges.len(), "location ranges");
+
+    ranges.iter().map(|range| range.start).min().expect("seeds")
+}
+
+/// Part 1 through the maps composed into one.
+fn part1_composed(input: &Input) -> u64 {
+    let composed = input.composed();
+    input
+        .seeds
+        .iter()
+        .map(|&seed| composed.apply(seed))
+        .min()
+        .expect("seeds")
+}
+
+/// Part 2 through the maps composed into one.
+fn part2_composed(input: &Input) -> u64 {
+    let composed = input.composed();
+    input
+        .seed_ranges()
+        .flat_map(|range| composed.image(range))
+        .map(|range| range.start)
+        .min()
+        .expect("seeds")
+}
+
+#[derive(Debug)]
+struct Map {
+    from: String,
+    to: String,
+    ranges: Vec<Range>,
+}
+
+impl Map {
+    fn new(from: &str, to: &str, ranges: Vec<Range>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            ranges,
+        }
+    }
+
+    fn apply(&self, n: u64) -> u64 {
+        self.matching(n)
+            .and_then(|range| range.apply(n))
+            .unwrap_or(n)
+    }
+
+    /// The range that moves `n`, if any does.
+    fn matching(&self, n: u64) -> Option<&Range> {
+        self.ranges.iter().find(|range| range.apply(n).is_some())
+    }
+
+    /// The same mapping, defined on every number.
+    fn piecewise(&self) -> Piecewise {
+        // between two consecutive range edges, every number is shifted by the same amount
+        let mut cuts = vec![0, u64::MAX];
+        for range in &self.ranges {
+            cuts.extend([range.source_start, range.source_start + range.length]);
+        }
+        cuts.sort_unstable();
+        cuts.dedup();
+        Piecewise::new(
+            cuts.windows(2)
+                .map(|w| Segment {
+                    source: w[0]..w[1],
+                    dest: self.apply(w[0]),
+                })
+                .collect(),
+        )
+    }
+
+    /// Where every number in `input` goes, split wherever it crosses the edge of a range.
+    fn apply_range(&self, input: ops::Range<u64>) -> Vec<ops::Range<u64>> {
+        let mut mapped = Vec::new();
+        let mut unmapped = vec![input];
+        for range in &self.ranges {
+            let mut rest = Vec::new();
+            for piece in unmapped {
+                let (inside, outside) = range.apply_range(piece);
+                mapped.extend(inside);
+                rest.extend(outside.into_iter().filter(|r| !r.is_empty()));
+            }
+            unmapped = rest;
+        }
+        // anything no range covers keeps its number
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Range {
+    dest_start: u64,
+    source_start: u64,
+    length: u64,
+    /// where it was in the input, or 0 if it wasn't read from one
+    line: usize,
+}
+
+impl Range {
+    fn new(dest_start: u64, source_start: u64, length: u64) -> Self {
+        Self {
+            dest_start,
+            source_start,
+            length,
+            line: 0,
+        }
+    }
+
+    fn apply(&self, n: u64) -> Option<u64> {
+        if n >= self.source_start {
+            let idx = n - self.source_start;
+            if idx < self.length {
+                Some(self.dest_start + idx)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The part of `input` this range covers, moved to its destination, and the parts before
+    /// and after it that it doesn't cover, which may be empty.
+    fn apply_range(
+        &self,
+        input: ops::Range<u64>,
+    ) -> (Option<ops::Range<u64>>, [ops::Range<u64>; 2]) {
+        let source_end = self.source_start + self.length;
+        let start = input.start.max(self.source_start);
+        let end = input.end.min(source_end);
+        let before = input.start..start.min(input.end);
+        let after = end.max(input.start)..input.end;
+        let shift = |n: u64| n - self.source_start + self.dest_start;
+        let inside = (start < end).then(|| shift(start)..shift(end));
+        (inside, [before, after])
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} {} {}",
+            self.line, self.dest_start, self.source_start, self.length
+        )
+    }
+}
+
+/// The number a seed has in each category, from the seed itself to its location, and the
+/// range that moved it at each step.
+///
+/// Displays a line per category, like `soil 81 by line 5: 52 50 48`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    category: String,
+    value: u64,
+    /// `None` where no range covers the number, so it keeps it
+    by: Option<Range>,
+}
+
+impl Provenance {
+    pub fn seed(&self) -> u64 {
+        self.steps[0].value
+    }
+
+    pub fn location(&self) -> u64 {
+        self.steps[self.steps.len() - 1].value
+    }
+
+    /// The category and number at each step.
+    pub fn values(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.steps
+            .iter()
+            .map(|step| (step.category.as_str(), step.value))
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            write!(f, "{} {}", step.category, step.value)?;
+            if let Some(range) = &step.by {
+                write!(f, " by {range}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// `maps` one after another as a single function.
+fn compose<'a>(maps: impl IntoIterator<Item = &'a Map>) -> Piecewise {
+    maps.into_iter()
+        .map(Map::piecewise)
+        .fold(Piecewise::identity(), |composed, next| composed.then(&next))
+}
+
+/// A function on every number below `u64::MAX`, made of intervals that are each shifted by
+/// their own amount, like an almanac map that says where everything goes.
+///
+/// Displays as the almanac would write it, `dest source length` for each interval that moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    /// in order, starting at 0 and each running up to the next
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    source: ops::Range<u64>,
+    dest: u64,
+}
+
+impl Segment {
+    fn shifted(&self, range: ops::Range<u64>) -> ops::Range<u64> {
+        let start = range.start - self.source.start + self.dest;
+        start..start + (range.end - range.start)
+    }
+
+    fn dest_range(&self) -> ops::Range<u64> {
+        self.shifted(self.source.clone())
+    }
+}
+
+impl Piecewise {
+    fn identity() -> Self {
+        Self::new(vec![Segment {
+            source: 0..u64::MAX,
+            dest: 0,
+        }])
+    }
+
+    /// Merges neighbouring segments that are shifted by the same amount.
+    fn new(segments: Vec<Segment>) -> Self {
+        let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == segment.source.start
+                        && last.dest_range().end == segment.dest =>
+                {
+                    last.source.end = segment.source.end;
+                }
+                _ => merged.push(segment),
+            }
+        }
+        Self { segments: merged }
+    }
+
+    /// The parts of `range` in each segment, in order, with the segment they're in.
+    fn pieces(
+        &self,
+        range: ops::Range<u64>,
+    ) -> impl Iterator<Item = (ops::Range<u64>, &Segment)> + '_ {
+        let first = self
+            .segments
+            .partition_point(|s| s.source.end <= range.start);
+        self.segments[first..]
+            .iter()
+            .take_while(move |s| s.source.start < range.end)
+            .map(move |s| {
+                let start = s.source.start.max(range.start);
+                let end = s.source.end.min(range.end);
+                (start..end, s)
+            })
+    }
+
+    pub fn apply(&self, n: u64) -> u64 {
+        let i = self.segments.partition_point(|s| s.source.end <= n);
+        self.segments
+            .get(i)
+            .map_or(n, |s| n - s.source.start + s.dest)
+    }
+
+    /// Where every number in `range` goes, one interval for each segment it crosses.
+    pub fn image(&self, range: ops::Range<u64>) -> Vec<ops::Range<u64>> {
+        self.pieces(range)
+            .map(|(piece, s)| s.shifted(piece))
+            .collect()
+    }
+
+    /// Every number that ends up in `range`, as intervals in order.
+    pub fn preimage(&self, range: ops::Range<u64>) -> Vec<ops::Range<u64>> {
+        let mut found = self
+            .segments
+            .iter()
+            .filter_map(|s| {
+                let dest = s.dest_range();
+                let start = dest.start.max(range.start);
+                let end = dest.end.min(range.end);
+                (start < end).then(|| {
+                    let from = start - s.dest + s.source.start;
+                    from..from + (end - start)
+                })
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|r| r.start);
+        found
+    }
+
+    /// `self` followed by `next`.
+    pub fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut segments = Vec::new();
+        for segment in &self.segments {
+            for (piece, after) in next.pieces(segment.dest_range()) {
+                let start = piece.start - segment.dest + segment.source.start;
+                segments.push(Segment {
+                    source: start..start + (piece.end - piece.start),
+                    dest: after.shifted(piece).start,
+                });
+            }
+        }
+        Piecewise::new(segments)
+    }
+
+    /// The function going back the other way, if every number comes from exactly one other.
+    pub fn inverse(&self) -> Option<Piecewise> {
+        let mut segments = self
+            .segments
+            .iter()
+            .map(|s| Segment {
+                source: s.dest_range(),
+                dest: s.source.start,
+            })
+            .collect::<Vec<_>>();
+        segments.sort_by_key(|s| s.source.start);
+        let mut at = 0;
+        for segment in &segments {
+            if segment.source.start != at {
+                return None;
+            }
+            at = segment.source.end;
+        }
+        (at == u64::MAX).then(|| Piecewise::new(segments))
+    }
+}
+
+impl fmt::Display for Piecewise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for s in self.segments.iter().filter(|s| s.source.start != s.dest) {
+            let len = s.source.end - s.source.start;
+            writeln!(f, "{} {} {len}", s.dest, s.source.start)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../input/2023/examples/day5/example.txt");
+
+    #[test]
+    fn apply_range_splits_at_range_edges() {
+        let map = Map::new(
+            "a",
+            "b",
+            vec![Range::new(50, 98, 2), Range::new(52, 50, 48)],
+        );
+        let mut mapped = map.apply_range(40..100);
+        mapped.sort_by_key(|r| r.start);
+        assert_eq!(vec![40..50, 50..52, 52..100], mapped);
+        assert_eq!(vec![10..20], map.apply_range(10..20));
+    }
+
+    #[test]
+    fn composes_the_maps() {
+        let input = generator(EXAMPLE).unwrap();
+        let composed = input.composed();
+        for seed in 0..120 {
+            let location = input.maps.iter().fold(seed, |n, map| map.apply(n));
+            assert_eq!(location, composed.apply(seed), "seed {seed}");
+        }
+
+        // the lowest location in part 2 comes from seed 82
+        let inverse = composed.inverse().unwrap();
+        assert_eq!(82, inverse.apply(46));
+        assert_eq!(vec![82..83], composed.preimage(46..47));
+        assert_eq!(composed, inverse.inverse().unwrap());
+        let mut back = composed.image(40..50);
+        back.sort_by_key(|r| r.start);
+        let seeds = back
+            .into_iter()
+            .flat_map(|r| composed.preimage(r))
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        assert_eq!((40..50).collect::<BTreeSet<_>>(), seeds);
+    }
+
+    #[test]
+    fn converts_between_any_categories() {
+        let input = generator(EXAMPLE).unwrap();
+        let converter = input.converter("soil", "humidity").unwrap();
+        let chain = [
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+        ];
+        for n in 0..120 {
+            let humidity = chain.windows(2).fold(n, |n, pair| {
+                let map = input.maps.iter().find(|m| m.from == pair[0]).unwrap();
+                assert_eq!(pair[1], map.to);
+                map.apply(n)
+            });
+            assert_eq!(humidity, converter.apply(n), "soil {n}");
+        }
+        assert_eq!(82, input.converter("location", "seed").unwrap().apply(46));
+        assert_eq!(
+            Piecewise::identity(),
+            input.converter("water", "water").unwrap()
+        );
+
+        assert_eq!(
+            Err(ConvertError::UnknownCategory("fuel".to_string())),
+            input.converter("seed", "fuel")
+        );
+        let broken = Input {
+            seeds: vec![1, 2],
+            maps: vec![
+                Map::new("seed", "soil", Vec::new()),
+                Map::new("water", "light", Vec::new()),
+            ],
+            chain: Vec::new(),
+        };
+        assert_eq!(
+            Err(ConvertError::NoPath {
+                from: "seed".to_string(),
+                to: "light".to_string()
+            }),
+            broken.converter("seed", "light")
+        );
+    }
+
+    #[test]
+    fn validate_follows_the_headers() {
+        let mut blocks = EXAMPLE.split("\n\n").collect::<Vec<_>>();
+        blocks[1..].reverse();
+        let shuffled = blocks.join("\n\n");
+        assert!(validate(&shuffled).is_empty());
+        let input = generator(&shuffled).unwrap();
+        assert_eq!(35, part1(&input));
+        assert_eq!(46, part2(&input));
+
+        // blank lines in between and after don't make maps of their own
+        let spaced = format!("{}\n\n\n", EXAMPLE.replace("\n\n", "\n\n\n"));
+        assert_eq!(7, generator(&spaced).unwrap().maps.len());
+        assert!(validate(&spaced).is_empty());
+    }
+
+    #[test]
+    fn rejects_maps_that_dont_lead_to_location() {
+        let err = |maps: &str| {
+            generator(&format!("seeds: 1 2\n\n{maps}"))
+                .err()
+                .map(|err| err.to_string())
+        };
+        assert_eq!(None, err("seed-to-location map:\n0 0 1"));
+        assert_eq!(
+            Some(
+                "day 5, line 6, column 1: expected a map from \"seed\" or a category it leads \
+                 to, found \"water-to-location map:\""
+                    .to_string()
+            ),
+            err("seed-to-soil map:\n0 0 1\n\nwater-to-location map:\n0 0 1")
+        );
+        assert_eq!(
+            Some(
+                "day 5, line 4, column 1: expected the only map from \"seed\", found \
+                 \"seed-to-water map:\""
+                    .to_string()
+            ),
+            err("seed-to-location map:\nseed-to-water map:")
+        );
+        assert_eq!(
+            Some(
+                "day 5, line 3, column 18: expected a map to \"location\", found end of line"
+                    .to_string()
+            ),
+            err("seed-to-soil map:")
+        );
+        assert_eq!(
+            Some(
+                "day 5, line 4, column 1: expected a range that ends within u64, found \
+                 \"0 18446744073709551615 2\""
+                    .to_string()
+            ),
+            err("seed-to-location map:\n0 18446744073709551615 2")
+        );
+        assert_eq!(
+            Some(
+                "day 5, line 1, column 12: expected a seed range that ends within u64, found \
+                 \"18446744073709551610 10\""
+                    .to_string()
+            ),
+            generator("seeds: 1 2 18446744073709551610 10\n\nseed-to-location map:")
+                .err()
+                .map(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn displays_like_the_almanac() {
+        let map = Map::new(
+            "a",
+            "b",
+            vec![Range::new(50, 98, 2), Range::new(52, 50, 48)],
+        );
+        assert_eq!("52 50 48\n50 98 2\n", map.piecewise().to_string());
+        // two maps that undo each other compose to nothing
+        let back = Map::new(
+            "b",
+            "a",
+            vec![Range::new(98, 50, 2), Range::new(50, 52, 48)],
+        );
+        assert_eq!("", map.piecewise().then(&back.piecewise()).to_string());
+        assert_eq!(
+            None,
+            Map::new("a", "b", vec![Range::new(0, 5, 5)])
+                .piecewise()
+                .inverse()
+        );
+    }
+
+    #[test]
+    fn explains_the_lowest_location() {
+        let input = generator(EXAMPLE).unwrap();
+        let lowest = input.lowest();
+        assert_eq!((13, 35), (lowest.seed(), lowest.location()));
+        assert_eq!(
+            "seed 13\n\
+             soil 13\n\
+             fertilizer 52 by line 10: 39 0 15\n\
+             water 41 by line 14: 0 11 42\n\
+             light 34 by line 20: 18 25 70\n\
+             temperature 34\n\
+             humidity 35 by line 29: 1 0 69\n\
+             location 35\n",
+            lowest.to_string()
+        );
+
+        assert_eq!(
+            Some(lowest.to_string()),
+            <Day5 as Solution>::explain(&input, 1)
+        );
+
+        let lowest = input.lowest_in_ranges();
+        assert_eq!(
+            vec![
+                ("seed", 82),
+                ("soil", 84),
+                ("fertilizer", 84),
+                ("water", 84),
+                ("light", 77),
+                ("temperature", 45),
+                ("humidity", 46),
+                ("location", 46)
+            ],
+            lowest.values().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn alternatives_agree() {
+        let input = generator(EXAMPLE).unwrap();
+        assert_eq!(35, part1_composed(&input));
+        assert_eq!(46, part2_composed(&input));
+        assert_eq!(part1(&input), part1_composed(&input));
+        assert_eq!(part2(&input), part2_composed(&input));
+    }
+
+    #[test]
+    fn ranges_agree_with_single_seeds() {
+        let Input { maps, .. } = generator(EXAMPLE).unwrap();
+        for range in [0..100, 13..14, 55..68, 79..93] {
+            let mut ends = range
+                .clone()
+                .map(|seed| maps.iter().fold(seed, |n, map| map.apply(n)))
+                .collect::<Vec<_>>();
+            ends.sort();
+            let mut from_ranges = vec![range];
+            for map in &maps {
+                from_ranges = from_ranges
+                    .into_iter()
+                    .flat_map(|r| map.apply_range(r))
+                    .collect();
+            }
+            let mut expanded = from_ranges.into_iter().flatten().collect::<Vec<_>>();
+            expanded.sort();
+            assert_eq!(ends, expanded);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use num::{BigUint, Zero};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::{debug, trace},
+    validate::Finding,
+};
+
+/// Each race's time and record distance, for part 1.
+pub struct Races {
+    times: Vec<BigUint>,
+    distances: Vec<BigUint>,
+    /// the time and distance with each line's digits run together, for part 2
+    joined: (BigUint, BigUint),
+}
+
+#[aoc_generator(day6)]
+fn parse(input: &str) -> Result<Races, ParseError> {
+    let src = Source::new(6, input);
+    let mut lines = src.lines();
+    let mut row = |label: &str| -> Result<(&str, Vec<BigUint>, BigUint), ParseError> {
+        let line = src.next(&mut lines, label)?;
+        let rest = src.strip_prefix(line, label)?;
+        let numbers = rest.split_whitespace().collect::<Vec<_>>();
+        if numbers.is_empty() {
+            return Err(src.error(rest, "a number"));
+        }
+        let each = numbers
+            .iter()
+            .map(|s| src.parse(s, "a number"))
+            .collect::<Result<Vec<_>, _>>()?;
+        let joined = numbers
+            .concat()
+            .parse()
+            .map_err(|_| src.error(rest, "numbers"))?;
+        Ok((line, each, joined))
+    };
+    let (_, times, time) = row("Time:")?;
+    let (line, distances, distance) = row("Distance:")?;
+    if times.len() != distances.len() {
+        return Err(src.error(line, format!("{} distances, one per time", times.len())));
+    }
+    Ok(Races {
+        times,
+        distances,
+        joined: (time, distance),
+    })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    match parse(input) {
+        Ok(_) => Vec::new(),
+        Err(err) => vec![err.into()],
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day6, part1)]
+fn part1(races: &Races) -> BigUint {
+    races
+        .times
+        .iter()
+        .zip(&races.distances)
+        .map(|(time, distance)| {
+            let ways = wins(time, distance).map_or_else(BigUint::zero, |w| w.count);
+            trace!(%time, %distance, %ways, "race");
+            ways
+        })
+        .product()
+}
+
+#[aoc(day6, part2)]
+fn part2(races: &Races) -> BigUint {
+    let (time, distance) = &races.joined;
+    let wins = wins(time, distance);
+    debug!(%time, %distance, ?wins, "race");
+    wins.map_or_else(BigUint::zero, |w| w.count)
+}
+
+/// The hold times that beat a race's record, `first..=last`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wins {
+    pub first: BigUint,
+    pub last: BigUint,
+    pub count: BigUint,
+}
+
+/// Every way to beat `distance` in a race lasting `time`, or `None` if there isn't one.
+///
+/// Holding for `t` goes `t * (time - t)`, which beats `distance` strictly between the roots of
+/// `t² - time * t + distance`, at `(time ± √(time² - 4 * distance)) / 2`.
+pub fn wins(time: &BigUint, distance: &BigUint) -> Option<Wins> {
+    let beats = |t: &BigUint| t <= time && t * (time - t) > *distance;
+    let squared = time * time;
+    let four_d = distance * 4u8;
+    if squared <= four_d {
+        return None;
+    }
+    // the floored root puts this at or just past the first whole number after the lower root
+    let root = (squared - four_d).sqrt();
+    let mut first = (time - &root) / 2u8;
+    if !beats(&first) {
+        first += 1u8;
+    }
+    if !beats(&first) {
+        return None;
+    }
+    // the roots are symmetric about time / 2
+    let last = time - &first;
+    let count = &last - &first + 1u8;
+    Some(Wins { first, last, count })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_counting_every_hold_time() {
+        for time in 0..60u64 {
+            for distance in 0..time * time / 4 + 2 {
+                let beating = (0..=time)
+                    .filter(|t| t * (time - t) > distance)
+                    .collect::<Vec<_>>();
+                let expected = beating.first().map(|&first| Wins {
+                    first: first.into(),
+                    last: (*beating.last().unwrap()).into(),
+                    count: beating.len().into(),
+                });
+                assert_eq!(
+                    expected,
+                    wins(&time.into(), &distance.into()),
+                    "time {time}, distance {distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_races_that_dont_line_up() {
+        let err = |input| parse(input).err().map(|err| err.to_string());
+        assert_eq!(None, err("Time: 7 15\nDistance: 9 40"));
+        assert_eq!(
+            Some("day 6, line 2, column 1: expected 2 distances, one per time, found \"Distance: 9\"".to_string()),
+            err("Time: 7 15\nDistance: 9")
+        );
+        assert_eq!(
+            Some("day 6, line 1, column 7: expected a number, found \"x 15\"".to_string()),
+            err("Time: x 15\nDistance: 9 40")
+        );
+        assert!(err("Time: 7 15").is_some());
+        assert!(err("Time:\nDistance:").is_some());
+    }
+
+    #[test]
+    fn wins_races_past_u64() {
+        // 10^30 hold times would take a while to count one by one
+        let time = BigUint::from(10u8).pow(30) * 2u8;
+        let distance = BigUint::from(10u8).pow(60) - 1u8;
+        let wins = wins(&time, &distance).unwrap();
+        assert_eq!(BigUint::from(1u8), wins.count);
+        assert_eq!(BigUint::from(10u8).pow(30), wins.first);
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::{self, Write},
+};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::Finding,
+};
+
+const CARDS: &str = "AKQJT98765432";
+
+#[aoc_generator(day7)]
+fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+    let src = Source::new(7, input);
+    let mut seen = HashSet::new();
+    src.lines()
+        .map(|line| {
+            let hand = Hand::parse(&src, line)?;
+            // ranking needs a strict order, so no hand can appear twice
+            if !seen.insert(hand.cards) {
+                return Err(src.error(line, "a hand that hasn't been dealt already"));
+            }
+            Ok(hand)
+        })
+        .collect()
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(7, input);
+    let mut findings = Vec::new();
+    let mut seen = HashMap::new();
+    for line in src.lines() {
+        let (cards, bid) = match src.split_once(line, " ") {
+            Ok(parts) => parts,
+            Err(err) => {
+                findings.push(err.into());
+                continue;
+            }
+        };
+        if let Some(i) = cards.find(|c| !CARDS.contains(c)) {
+            findings.push(src.error(&cards[i..], "a card").into());
+        }
+        if cards.chars().count() != 5 {
+            findings.push(Finding::at(&src, cards, "hand doesn't have five cards"));
+        }
+        if let Err(err) = src.parse::<usize>(bid, "a bid") {
+            findings.push(err.into());
+        }
+        // ranking needs a strict order, so no hand can appear twice
+        if let Some(first) = seen.insert(cards, src.locate(line).0) {
+            findings.push(Finding::at(
+                &src,
+                line,
+                format!("same hand as line {first}"),
+            ));
+        }
+    }
+    findings
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day7, part1)]
+fn part1(input: &[Hand]) -> usize {
+    let mut hands = input.to_vec();
+    hands.sort_unstable();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| {
+            let rank = i + 1;
+            trace!(%hand, rank);
+            hand.bid * rank
+        })
+        .sum()
+}
+
+#[aoc(day7, part2)]
+fn part2(input: &[Hand]) -> usize {
+    let mut hands = input.iter().map(Hand::with_jokers).collect::<Vec<_>>();
+    hands.sort_unstable();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| {
+            let rank = i + 1;
+            trace!(%hand, rank);
+            hand.bid * rank
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand {
+    ty: HandType,
+    cards: [Card; 5],
+    bid: usize,
+}
+
+impl Hand {
+    /// A hand scored without jokers.
+    fn parse<'a>(src: &Source<'a>, line: &'a str) -> Result<Self, ParseError> {
+        let (cards_str, bid) = src.split_once(line, " ")?;
+        if let Some(i) = cards_str.find(|c| !CARDS.contains(c)) {
+            return Err(src.error(&cards_str[i..], "a card"));
+        }
+        let cards: [Card; 5] = cards_str
+            .chars()
+            .map(Card::from)
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| src.error(cards_str, "five cards"))?;
+        Ok(Self {
+            ty: HandType::from_hand(&cards, false),
+            cards,
+            bid: src.parse(bid, "a bid")?,
+        })
+    }
+
+    /// The same hand scored with its J cards as jokers.
+    fn with_jokers(&self) -> Self {
+        Self {
+            ty: HandType::from_hand(&self.cards, true),
+            ..self.clone()
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let order = self.ty.cmp(&other.ty);
+        if order.is_ne() {
+            order
+        } else {
+            for (s, o) in self.cards.iter().zip(other.cards.iter()) {
+                let order = s.v.cmp(&o.v);
+                if order.is_ne() {
+                    return order;
+                }
+            }
+            panic!("equal hands");
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.cards {
+            f.write_char(c.c)?;
+        }
+        f.write_char(' ')?;
+        f.write_fmt(format_args!("{:?}", self.ty))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Card {
+    c: char,
+    v: u8,
+}
+
+impl From<char> for Card {
+    fn from(c: char) -> Self {
+        let v = match c {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' => 1,
+            'T' => 10,
+            '9' => 9,
+            '8' => 8,
+            '7' => 7,
+            '6' => 6,
+            '5' => 5,
+            '4' => 4,
+            '3' => 3,
+            '2' => 2,
+            _ => panic!("invalid char {c:?}"),
+        };
+
+        Self { c, v }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+enum HandType {
+    FiveOfKind = 7,
+    FourOfKind = 6,
+    FullHouse = 5,
+    ThreeOfKind = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1,
+}
+
+impl HandType {
+    fn from_hand(hand: &[Card], with_jokers: bool) -> Self {
+        let mut counts: HashMap<Card, u8> = HashMap::new();
+        for c in hand {
+            let entry = counts.entry(*c).or_default();
+            *entry += 1;
+        }
+
+        if with_jokers {
+            if let Some(num_jokers) = counts.remove(&Card::from('J')) {
+                // if we have jokers, find the card we have the most of and add the number of
+                // jokers to our count of that card
+                let mut rest = counts.clone().into_iter().collect::<Vec<_>>();
+                rest.sort_by_key(|(_key, count)| *count);
+                if let Some((card, _count)) = rest.pop() {
+                    let c = counts.get_mut(&card).expect("nope");
+                    *c += num_jokers;
+                } else {
+                    // there are no non-jokers, so just put them back and score normally
+                    counts.insert(Card::from('J'), num_jokers);
+                }
+            }
+        }
+
+        let mut counts: Vec<u8> = counts.into_values().collect();
+        counts.sort();
+        counts.reverse();
+        match &counts[..] {
+            [5] => HandType::FiveOfKind,
+            [4, 1] => HandType::FourOfKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::OnePair,
+            [1, 1, 1, 1, 1] => HandType::HighCard,
+            _ => panic!("impossible: {counts:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_hands_that_cant_be_ranked() {
+        let err = |input| parse(input).err().unwrap().to_string();
+        assert_eq!(
+            "day 7, line 1, column 4: expected a card, found \"X2 5\"",
+            err("32TX2 5")
+        );
+        assert_eq!(
+            "day 7, line 1, column 1: expected five cards, found \"32T 5\"",
+            err("32T 5")
+        );
+        assert_eq!(
+            "day 7, line 2, column 1: expected a hand that hasn't been dealt already, found \"32T3K 7\"",
+            err("32T3K 5\n32T3K 7")
+        );
+        let hands = parse("KK677 28\nKTJJT 220").unwrap();
+        assert_eq!((276, 468), (part1(&hands), part2(&hands)));
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+use num::{integer::ExtendedGcd, Integer};
+
+use crate::{
+    answer::Answer,
+    cycle,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::{debug, trace},
+    validate::Finding,
+};
+
+pub struct Input {
+    directions: Vec<char>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+#[aoc_generator(day8)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(8, input);
+    let mut lines = src.lines();
+    let instructions = src.next(&mut lines, "a line of instructions")?;
+    if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+        return Err(src.error(&instructions[i..], "'L' or 'R'"));
+    }
+    let directions = instructions.chars().collect();
+    let blank = src.next(&mut lines, "a blank line")?;
+    if !blank.is_empty() {
+        return Err(src.error(blank, "a blank line"));
+    }
+    let nodes = lines
+        .map(|line| {
+            let (id, dirs) = src.split_once(line, " = ")?;
+            let dirs = src.strip_prefix(dirs, "(")?;
+            let (left, right) = src.split_once(dirs, ", ")?;
+            let (right, _) = src.split_once(right, ")")?;
+            Ok((id.to_string(), (left.to_string(), right.to_string())))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Input { directions, nodes })
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(8, input);
+    let nodes = match parse(input) {
+        Ok(Input { nodes, .. }) => nodes,
+        Err(err) => return vec![err.into()],
+    };
+
+    let mut findings = Vec::new();
+    for line in src.lines().skip(2) {
+        let (id, _) = line.split_once(" = ").unwrap_or_default();
+        let (left, right) = &nodes[id];
+        for next in [left, right] {
+            if !nodes.contains_key(next) {
+                findings.push(Finding::at(
+                    &src,
+                    line,
+                    format!("{id} leads to unknown node {next}"),
+                ));
+            }
+        }
+    }
+    for id in ["AAA", "ZZZ"] {
+        if !nodes.contains_key(id) {
+            findings.push(Finding::at(&src, src.end(), format!("no node {id}")));
+        }
+    }
+    if !nodes.keys().any(|id| id.ends_with('A')) {
+        findings.push(Finding::at(&src, src.end(), "no node ending in A"));
+    }
+    findings
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[aoc(day8, part1)]
+fn part1(input: &Input) -> u32 {
+    let Input { directions, nodes } = input;
+
+    let mut directions = std::iter::repeat(directions).flatten();
+
+    let mut id = "AAA";
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        let next_dir = directions.next().unwrap();
+        id = match next_dir {
+            'L' => &nodes[id].0,
+            'R' => &nodes[id].1,
+            _ => panic!("bad dir"),
+        };
+        trace!(steps, id);
+        if id == "ZZZ" {
+            break;
+        }
+    }
+    steps
+}
+
+#[aoc(day8, part2)]
+fn part2(input: &Input) -> usize {
+    let Input { directions, nodes } = input;
+
+    let mut steps = vec![(0, 1)];
+    let mut min_step = 1;
+    for start in nodes.keys().filter(|k| k.ends_with('A')) {
+        let cycle = cycle::find_cycle((start.as_str(), 0), |&(pos, dir_idx)| {
+            let next = match directions[dir_idx] {
+                'L' => &nodes[pos].0,
+                'R' => &nodes[pos].1,
+                _ => panic!("bad dir"),
+            };
+            (next.as_str(), (dir_idx + 1) % directions.len())
+        });
+
+        // a ghost only reaches a Z node on steps congruent to one it visits inside its loop. Zs
+        // in the tail before the loop can only be hit once, so they're ignored.
+        let loop_len = cycle.cycle_len as i128;
+        let zs = (cycle.tail_len..cycle.tail_len + cycle.cycle_len)
+            .filter(|&n| cycle.state_at(n).0.ends_with('Z'))
+            .map(|n| n as i128 % loop_len)
+            .collect::<Vec<_>>();
+        debug!(
+            start,
+            tail = cycle.tail_len,
+            len = cycle.cycle_len,
+            ?zs,
+            "ghost loop"
+        );
+        steps = steps
+            .into_iter()
+            .cartesian_product(zs)
+            .filter_map(|(step, z)| combine(step, (z, loop_len)))
+            .unique()
+            .collect();
+        min_step = min_step.max(cycle.tail_len as i128);
+    }
+
+    steps
+        .into_iter()
+        .map(|(r, m)| r + Integer::div_ceil(&(min_step - r).max(0), &m) * m)
+        .min()
+        .expect("no step where every ghost is on a Z") as usize
+}
+
+/// Merges `x = a (mod m)` and `x = b (mod n)` into a single congruence, if one exists.
+fn combine((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+    let lcm = m / gcd * n;
+    let k = ((b - a) / gcd * x).rem_euclid(n / gcd);
+    Some(((a + m * k).rem_euclid(lcm), lcm))
+}
@@ -1,17 +1,56 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::{
+    answer::Answer,
+    parse::{ParseError, Source},
+    solution::Solution,
+    trace::trace,
+    validate::{self, Finding},
+};
+
 #[aoc_generator(day9)]
-fn parse(input: &str) -> Vec<Vec<i64>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|s| s.parse::<i64>().expect("parse reading"))
-                .collect()
-        })
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let src = Source::new(9, input);
+    src.lines().map(|line| parse_history(&src, line)).collect()
+}
+
+fn parse_history<'a>(src: &Source<'a>, line: &'a str) -> Result<Vec<i64>, ParseError> {
+    if line.trim().is_empty() {
+        return Err(src.error(line, "a reading"));
+    }
+    line.split_whitespace()
+        .map(|s| src.parse::<i64>(s, "a reading"))
         .collect()
 }
 
+pub(crate) fn validate(input: &str) -> Vec<Finding> {
+    let src = Source::new(9, input);
+    let mut findings = Vec::new();
+    validate::parse_each(src.lines(), |line| parse_history(&src, line), &mut findings);
+    findings
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
 #[aoc(day9, part1)]
 fn part1(input: &[Vec<i64>]) -> i64 {
     let mut next_values = Vec::new();
@@ -31,6 +70,7 @@ fn part1(input: &[Vec<i64>]) -> i64 {
             last += row.last().unwrap();
             row.push(last);
         }
+        trace!(?line, depth = rows.len(), extrapolated = last);
         next_values.push(*rows[0].last().unwrap());
     }
     next_values.iter().sum()
@@ -56,29 +96,8 @@ fn part2(input: &[Vec<i64>]) -> i64 {
             last += row.last().unwrap();
             row.push(last);
         }
+        trace!(?line, depth = rows.len(), extrapolated = last);
         next_values.push(*rows[0].last().unwrap());
     }
     next_values.iter().sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example() {
-        assert_eq!(
-            part1(&parse(
-                "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45"
-            )),
-            114
-        );
-    }
-
-    #[test]
-    fn part2_example() {
-        assert_eq!(part2(&parse("<EXAMPLE>")), 42);
-    }
-}
@@ -0,0 +1,48 @@
+//! Checks every registered solution that has recorded answers against its real input. Record
+//! new answers with `cargo run --release --bin record` once a change is verified.
+
+use std::fs;
+
+use aoc::{answers::Answers, solution::YEARS};
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "too slow unoptimised, run with --release")]
+fn real_inputs_give_recorded_answers() {
+    let mut problems = Vec::new();
+    for days in YEARS {
+        let answers = Answers::load(&Answers::path(days[0].year)).expect("answers file");
+        for day in days.iter() {
+            let input = match fs::read_to_string(day.input_path()) {
+                Ok(input) => input,
+                // days without recorded answers may not have an input yet either
+                Err(_)
+                    if [1, 2]
+                        .iter()
+                        .all(|&part| answers.get(day.day, part).is_none()) =>
+                {
+                    continue
+                }
+                Err(err) => {
+                    let path = day.input_path();
+                    problems.push(format!(
+                        "{} day {}: can't read {}: {err}",
+                        day.year,
+                        day.day,
+                        path.display()
+                    ));
+                    continue;
+                }
+            };
+            match answers.check(day, input.trim_end_matches('\n')) {
+                Ok(mismatches) => problems.extend(mismatches.iter().map(|m| m.to_string())),
+                Err(err) => problems.push(err),
+            }
+        }
+    }
+
+    assert!(
+        problems.is_empty(),
+        "answers changed:\n{}",
+        problems.join("\n")
+    );
+}
@@ -0,0 +1,14 @@
+//! The puzzle examples under `input/YEAR/examples`, one test per example and part, generated by
+//! `build.rs`.
+
+use aoc::solution;
+
+fn check(year: u16, day: u8, part: u8, input: &str, expected: &str) {
+    let day = solution::find(year, day).expect("a registered day");
+    let answer = day
+        .solve(part, input.trim_end_matches('\n'))
+        .unwrap_or_else(|err| panic!("{err}"));
+    assert_eq!(expected, answer);
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));